@@ -0,0 +1,80 @@
+use std::env;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub struct DataConfig {
+    pub data_dir: PathBuf,
+    pub projects_file: PathBuf,
+    /// Optional JSON object mapping old titles or ids to current project ids
+    pub aliases_file: PathBuf,
+    pub source: ProjectSource,
+    /// How often the projects file is checked for changes; 0 disables the watcher
+    pub watch_interval_secs: u64,
+    /// A reload is refused when more than this share of records is rejected
+    pub max_rejected_ratio: f64,
+    /// Number of previous snapshots kept in memory; older ones are read back from disk
+    pub snapshot_retention: usize,
+}
+
+/// Where the served project dataset is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectSource {
+    Json,
+    Postgres,
+}
+
+impl DataConfig {
+    /// Directory where every imported dataset is archived as a snapshot
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.data_dir.join("snapshots")
+    }
+
+    /// Directory holding the website, social, team and region enrichment files
+    pub fn enriched_dir(&self) -> PathBuf {
+        self.data_dir.join("enriched")
+    }
+
+    pub fn from_env() -> Self {
+        let data_dir = PathBuf::from(env::var("DATA_DIR").unwrap_or_else(|_| "data".to_string()));
+
+        // PROJECTS_FILE may be absolute or relative to the working directory
+        let projects_file = env::var("PROJECTS_FILE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| data_dir.join("raw/all_projects_structured.json"));
+        let aliases_file = env::var("PROJECT_ALIASES_FILE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| data_dir.join("project_aliases.json"));
+
+        Self {
+            data_dir,
+            projects_file,
+            aliases_file,
+            source: ProjectSource::from_env(),
+            watch_interval_secs: env::var("DATASET_WATCH_INTERVAL_SECS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .unwrap_or(10),
+            max_rejected_ratio: env::var("DATASET_MAX_REJECTED_RATIO")
+                .unwrap_or_else(|_| "0.5".to_string())
+                .parse()
+                .unwrap_or(0.5),
+            snapshot_retention: env::var("DATASET_SNAPSHOT_RETENTION")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+        }
+    }
+}
+
+impl ProjectSource {
+    pub fn from_env() -> Self {
+        match env::var("PROJECT_SOURCE")
+            .unwrap_or_else(|_| "json".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "postgres" | "db" => Self::Postgres,
+            _ => Self::Json,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 mod app_config;
 mod db_config;
 mod auth_config;
+mod data_config;
 
 pub use app_config::*;
 pub use db_config::*;
 pub use auth_config::*;
+pub use data_config::*;
@@ -6,10 +6,12 @@ use serde::{Deserialize, Serialize};
 pub struct UserCtx {
     pub user_id: Uuid,
     pub email: String,
+    /// May use the `/api/admin` routes
+    pub is_admin: bool,
 }
 
 impl UserCtx {
-    pub fn new(user_id: Uuid, email: String) -> Self {
-        Self { user_id, email }
+    pub fn new(user_id: Uuid, email: String, is_admin: bool) -> Self {
+        Self { user_id, email, is_admin }
     }
 }
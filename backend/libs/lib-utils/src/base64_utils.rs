@@ -1,4 +1,7 @@
-use base64::{engine::general_purpose::STANDARD, Engine as _};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
 
 pub fn encode(data: &[u8]) -> String {
     STANDARD.encode(data)
@@ -17,3 +20,12 @@ pub fn decode_string(data: &str) -> Result<String, String> {
         .map_err(|e| e.to_string())
         .and_then(|bytes| String::from_utf8(bytes).map_err(|e| e.to_string()))
 }
+
+/// Unpadded URL-safe alphabet, for values passed in query strings
+pub fn encode_url_safe(data: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(data)
+}
+
+pub fn decode_url_safe(data: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    URL_SAFE_NO_PAD.decode(data)
+}
@@ -20,6 +20,10 @@ pub enum AppError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
+    /// A query language string that failed to parse; the response carries its position
+    #[error("Invalid query: {0}")]
+    InvalidQuery(shared::QueryError),
+
     #[error("Internal server error: {0}")]
     InternalError(String),
 
@@ -35,11 +39,20 @@ pub enum AppError {
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let details = match &self {
+            Self::InvalidQuery(err) => Some(json!({
+                "position": err.position,
+                "length": err.length,
+            })),
+            _ => None,
+        };
+
         let (status, code, message) = match self {
             Self::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED", msg),
             Self::Forbidden(msg) => (StatusCode::FORBIDDEN, "FORBIDDEN", msg),
             Self::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg),
             Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, "BAD_REQUEST", msg),
+            Self::InvalidQuery(err) => (StatusCode::BAD_REQUEST, "INVALID_QUERY", err.message),
             Self::InternalError(msg) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR", msg)
             }
@@ -63,12 +76,14 @@ impl IntoResponse for AppError {
             ),
         };
 
-        let body = Json(json!({
-            "error": {
-                "code": code,
-                "message": message,
-            }
-        }));
+        let mut error = json!({
+            "code": code,
+            "message": message,
+        });
+        if let Some(details) = details {
+            error["details"] = details;
+        }
+        let body = Json(json!({ "error": error }));
 
         (status, body).into_response()
     }
@@ -36,6 +36,16 @@ pub async fn auth_middleware(
     Ok(next.run(req).await)
 }
 
+/// Middleware restricting routes to admins; layer it inside `auth_middleware`, which
+/// provides the `UserCtx`
+pub async fn admin_middleware(req: Request, next: Next) -> Result<Response, StatusCode> {
+    match req.extensions().get::<UserCtx>() {
+        Some(user) if user.is_admin => Ok(next.run(req).await),
+        Some(_) => Err(StatusCode::FORBIDDEN),
+        None => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
 /// Validate token and retrieve user context
 async fn validate_token_and_get_user(
     pool: &PgPool,
@@ -46,9 +56,9 @@ async fn validate_token_and_get_user(
     let token_hash = hash_token(token);
 
     // Query to get user from valid session
-    let result = sqlx::query_as::<_, (Uuid, String)>(
+    let result = sqlx::query_as::<_, (Uuid, String, bool)>(
         r#"
-        SELECT u.id, u.email
+        SELECT u.id, u.email, u.is_admin
         FROM users u
         INNER JOIN sessions s ON s.user_id = u.id
         WHERE s.token_hash = $1
@@ -62,7 +72,7 @@ async fn validate_token_and_get_user(
     .map_err(|e| format!("Database error: {}", e))?;
 
     match result {
-        Some((user_id, email)) => Ok(UserCtx::new(user_id, email)),
+        Some((user_id, email, is_admin)) => Ok(UserCtx::new(user_id, email, is_admin)),
         None => Err("Invalid or expired session".to_string()),
     }
 }
@@ -1,15 +1,222 @@
 use sqlx::PgPool;
-use std::sync::Arc;
-use shared::Project;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+use lib_core::{DataConfig, ProjectSource};
+use shared::{slugify, DatasetLoadReport, DatasetSnapshot, Project, RefusedLoad};
+
+use crate::services::{Enrichment, SearchIndex, SimilarityIndex, SuggestIndex};
+
+/// A loaded project dataset together with the report describing how it was loaded
+pub struct Dataset {
+    pub projects: Vec<Project>,
+    pub report: DatasetLoadReport,
+    pub snapshot: DatasetSnapshot,
+    pub source_modified: Option<SystemTime>,
+    /// Enrichment files joined by project id, loaded alongside the projects
+    pub enrichment: Enrichment,
+    /// Full-text index over the projects and their website enrichment
+    pub search: SearchIndex,
+    /// Autocomplete over titles, companies, tags, countries and programs
+    pub suggest: SuggestIndex,
+    /// TF-IDF vectors for "more like this"
+    pub similarity: SimilarityIndex,
+    /// Project id to position in `projects`
+    index: HashMap<String, usize>,
+    /// Titles and retired ids that still resolve to a current project id
+    aliases: HashMap<String, String>,
+}
+
+impl Dataset {
+    pub fn new(mut projects: Vec<Project>, mut report: DatasetLoadReport, checksum: String) -> Self {
+        for project in projects.iter_mut() {
+            for (field, value) in project.parse_fields() {
+                report.record_unknown(field, value);
+            }
+        }
+
+        let snapshot = DatasetSnapshot {
+            id: checksum.chars().take(12).collect(),
+            created_at: report.loaded_at,
+            checksum,
+            source_path: report.source_path.clone(),
+            project_count: projects.len(),
+            enrichment_checksum: None,
+        };
+
+        let mut index = HashMap::new();
+        let mut aliases = HashMap::new();
+        for (position, project) in projects.iter().enumerate() {
+            index.insert(project.id.clone(), position);
+            // With duplicate titles the first project keeps the title link
+            aliases.entry(project.title.clone()).or_insert_with(|| project.id.clone());
+            aliases.entry(slugify(&project.title)).or_insert_with(|| project.id.clone());
+        }
+        aliases.retain(|alias, _| !index.contains_key(alias));
+        let search = SearchIndex::build(&projects, &Enrichment::default());
+        let suggest = SuggestIndex::build(&projects);
+        let similarity = SimilarityIndex::build(&projects);
+
+        Self {
+            projects,
+            report,
+            snapshot,
+            source_modified: None,
+            enrichment: Enrichment::default(),
+            search,
+            suggest,
+            similarity,
+            index,
+            aliases,
+        }
+    }
+
+    /// Join enrichment and rebuild the search index to include the website text
+    pub fn set_enrichment(&mut self, enrichment: Enrichment) {
+        self.snapshot.enrichment_checksum = Some(enrichment.checksum.clone());
+        self.search = SearchIndex::build(&self.projects, &enrichment);
+        self.enrichment = enrichment;
+    }
+
+    /// Look up a project by its current id
+    pub fn project(&self, id: &str) -> Option<&Project> {
+        self.position(id).map(|position| &self.projects[position])
+    }
+
+    /// Position in `projects` of the project with this current id
+    pub fn position(&self, id: &str) -> Option<usize> {
+        self.index.get(id).copied()
+    }
+
+    /// Current id for an id, a title or a retired id
+    pub fn canonical_id<'a>(&'a self, key: &'a str) -> Option<&'a str> {
+        if self.index.contains_key(key) {
+            return Some(key);
+        }
+
+        self.aliases
+            .get(key)
+            .or_else(|| self.aliases.get(&slugify(key)))
+            .map(String::as_str)
+    }
+
+    /// Add aliases; existing aliases win, and none may shadow a current id or point at a missing one
+    pub fn add_aliases(&mut self, aliases: impl IntoIterator<Item = (String, String)>) {
+        for (alias, id) in aliases {
+            if self.index.contains_key(&id) && !self.index.contains_key(&alias) {
+                self.aliases.entry(alias).or_insert(id);
+            }
+        }
+    }
+
+    /// Carry over the aliases of the dataset this one replaces, plus the old titles of
+    /// projects whose id survived a rename
+    pub fn inherit_aliases(&mut self, previous: &Dataset) {
+        let renamed: Vec<(String, String)> = previous
+            .projects
+            .iter()
+            .filter(|old| {
+                self.project(&old.id)
+                    .is_some_and(|current| current.title != old.title)
+            })
+            .map(|old| (old.title.clone(), old.id.clone()))
+            .collect();
+
+        self.add_aliases(renamed);
+        self.add_aliases(previous.aliases.clone());
+    }
+}
+
+/// The current dataset plus the most recent previous snapshots
+struct DatasetStore {
+    current: Arc<Dataset>,
+    previous: VecDeque<Arc<Dataset>>,
+    /// The latest load refused by validation since `current` was accepted
+    refused: Option<RefusedLoad>,
+}
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: PgPool,
-    pub projects: Arc<Vec<Project>>,
+    pub data_config: Arc<DataConfig>,
+    /// Serializes reloads triggered by the watcher and the admin endpoint
+    pub reload_lock: Arc<tokio::sync::Mutex<()>>,
+    store: Arc<RwLock<DatasetStore>>,
 }
 
 impl AppState {
-    pub fn new(db: PgPool, projects: Arc<Vec<Project>>) -> Self {
-        Self { db, projects }
+    pub fn new(db: PgPool, data_config: DataConfig, dataset: Dataset) -> Self {
+        Self {
+            db,
+            data_config: Arc::new(data_config),
+            reload_lock: Arc::new(tokio::sync::Mutex::new(())),
+            store: Arc::new(RwLock::new(DatasetStore {
+                current: Arc::new(dataset),
+                previous: VecDeque::new(),
+                refused: None,
+            })),
+        }
+    }
+
+    /// Snapshot of the current dataset; stays valid even if a reload swaps it out
+    pub fn dataset(&self) -> Arc<Dataset> {
+        self.store
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .current
+            .clone()
+    }
+
+    /// The database to run project listings against: set when PostgreSQL holds the projects
+    /// and `dataset` is the current one, loaded from it
+    pub fn projects_db(&self, dataset: &Dataset) -> Option<&PgPool> {
+        let current = dataset.snapshot.id == self.dataset().snapshot.id;
+        (self.data_config.source == ProjectSource::Postgres && current).then_some(&self.db)
+    }
+
+    /// The current or a retained previous dataset with the given snapshot id
+    pub fn retained_dataset(&self, snapshot_id: &str) -> Option<Arc<Dataset>> {
+        let store = self.store.read().unwrap_or_else(|e| e.into_inner());
+
+        std::iter::once(&store.current)
+            .chain(store.previous.iter())
+            .find(|d| d.snapshot.id == snapshot_id)
+            .cloned()
+    }
+
+    /// Atomically replace the current dataset, keeping the old one as a previous snapshot
+    pub fn replace_dataset(&self, dataset: Dataset) {
+        let mut store = self.store.write().unwrap_or_else(|e| e.into_inner());
+
+        store.refused = None;
+        let old = std::mem::replace(&mut store.current, Arc::new(dataset));
+        if old.snapshot.id != store.current.snapshot.id {
+            store.previous.push_front(old);
+            store.previous.truncate(self.data_config.snapshot_retention);
+        }
+    }
+
+    /// Remember a load that failed validation; the current dataset keeps serving
+    pub fn refuse_load(&self, refused: RefusedLoad) {
+        self.store.write().unwrap_or_else(|e| e.into_inner()).refused = Some(refused);
+    }
+
+    /// The latest refused load, if none was accepted since
+    pub fn refused_load(&self) -> Option<RefusedLoad> {
+        self.store.read().unwrap_or_else(|e| e.into_inner()).refused.clone()
+    }
+
+    /// Keep a dataset read back from the archive so later pins don't hit the disk
+    pub fn retain_dataset(&self, dataset: Arc<Dataset>) {
+        let retention = self.data_config.snapshot_retention;
+        let mut store = self.store.write().unwrap_or_else(|e| e.into_inner());
+
+        if retention == 0 || store.previous.iter().any(|d| d.snapshot.id == dataset.snapshot.id) {
+            return;
+        }
+        if store.previous.len() >= retention {
+            store.previous.pop_back();
+        }
+        store.previous.push_back(dataset);
     }
 }
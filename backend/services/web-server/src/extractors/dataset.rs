@@ -0,0 +1,31 @@
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use lib_web::AppError;
+
+use crate::{services::SnapshotService, AppState, Dataset};
+
+#[derive(Debug, Deserialize)]
+struct SnapshotParams {
+    snapshot: Option<String>,
+}
+
+/// The dataset a request runs against: the current one, or the one pinned with `?snapshot=<id>`
+pub struct PinnedDataset(pub Arc<Dataset>);
+
+impl FromRequestParts<AppState> for PinnedDataset {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<SnapshotParams>::try_from_uri(&parts.uri)
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+        match params.snapshot {
+            Some(id) => Ok(Self(SnapshotService::resolve(state, &id).await?)),
+            None => Ok(Self(state.dataset())),
+        }
+    }
+}
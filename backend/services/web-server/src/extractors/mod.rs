@@ -1,5 +1,7 @@
-// Extractors removed - using middleware pattern instead
 // UserCtx is injected via auth_middleware and extracted from request extensions
 
+mod dataset;
+mod query;
 
-// No custom extractor needed - UserCtx comes from middleware
+pub use dataset::*;
+pub use query::*;
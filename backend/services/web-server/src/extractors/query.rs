@@ -0,0 +1,41 @@
+use axum::{extract::FromRequestParts, http::request::Parts};
+use serde::de::DeserializeOwned;
+use lib_web::AppError;
+
+/// Like `Query`, but repeated keys (`countries=US&countries=KE`) are joined with commas
+/// so list fields deserialized with `shared::deserialize_list` receive every value
+pub struct ListQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ListQuery<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parse_list_query(parts.uri.query().unwrap_or_default()).map(Self)
+    }
+}
+
+/// Deserialize a query string the way `ListQuery` does
+pub fn parse_list_query<T: DeserializeOwned>(query: &str) -> Result<T, AppError> {
+    let invalid = |e: &dyn std::fmt::Display| AppError::BadRequest(format!("Invalid query string: {}", e));
+
+    let pairs: Vec<(String, String)> =
+        serde_urlencoded::from_str(query.trim_start_matches('?')).map_err(|e| invalid(&e))?;
+
+    let mut merged: Vec<(String, String)> = Vec::new();
+    for (key, value) in pairs {
+        match merged.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => {
+                existing.push(',');
+                existing.push_str(&value);
+            }
+            None => merged.push((key, value)),
+        }
+    }
+
+    let query = serde_urlencoded::to_string(&merged).map_err(|e| invalid(&e))?;
+    serde_urlencoded::from_str(&query).map_err(|e| invalid(&e))
+}
@@ -1,11 +1,16 @@
 use axum::Router;
-use std::sync::Arc;
 use tracing::{error, info};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use lib_core::{create_pool, AppConfig, DbConfig};
+use lib_core::{create_pool, AppConfig, DataConfig, DbConfig, ProjectSource};
+use sqlx::PgPool;
 use lib_web::{cors_dev, cors_production, logger_middleware};
-use web_server::{create_routes, AppState};
+use shared::DatasetLoadReport;
+use web_server::{
+    create_admin_routes, create_routes, create_user_routes,
+    services::{DataQualityService, DatasetService, LoadError, SnapshotService},
+    AppState, Dataset,
+};
 
 #[tokio::main]
 async fn main() {
@@ -15,7 +20,8 @@ async fn main() {
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| "web_server=debug,tower_http=debug".into()),
         )
-        .with(tracing_subscriber::fmt::layer())
+        // Logs go to stderr so `web-server audit` output can be piped
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
         .init();
 
     // Load environment variables
@@ -38,36 +44,90 @@ async fn main() {
         }
     };
 
-    // Create database pool
-    let pool = match create_pool(&db_config).await {
-        Ok(pool) => {
-            info!(" Database connection established");
-            pool
+    let data_config = DataConfig::from_env();
+
+    // `web-server audit [--markdown]` prints a data quality report for the configured source and
+    // exits; it only reads, so it connects to the database only for the PostgreSQL source
+    if std::env::args().nth(1).as_deref() == Some("audit") {
+        let pool = match data_config.source {
+            ProjectSource::Postgres => Some(connect(&db_config).await),
+            ProjectSource::Json => None,
+        };
+        let dataset = match DatasetService::load_dataset(&data_config, pool.as_ref()).await {
+            Ok(dataset) => dataset,
+            Err(LoadError { error, refused }) => {
+                if let Some(refused) = refused {
+                    DatasetService::log_report(&refused.report);
+                }
+                error!("Failed to load projects: {}", error);
+                std::process::exit(1);
+            }
+        };
+
+        let report = DataQualityService::audit(&dataset);
+        if std::env::args().any(|arg| arg == "--markdown") {
+            println!("{}", DataQualityService::to_markdown(&report));
+        } else {
+            match serde_json::to_string_pretty(&report) {
+                Ok(json) => println!("{}", json),
+                Err(e) => {
+                    error!("Failed to serialize data quality report: {}", e);
+                    std::process::exit(1);
+                }
+            }
         }
-        Err(e) => {
-            error!("Failed to connect to database: {}", e);
-            error!("Make sure PostgreSQL is running and DATABASE_URL is set correctly");
-            std::process::exit(1);
+        std::process::exit(0);
+    }
+
+    // Create database pool
+    let pool = connect(&db_config).await;
+
+    // `web-server import` loads the projects file into PostgreSQL and exits
+    if std::env::args().nth(1).as_deref() == Some("import") {
+        match DatasetService::import_to_postgres(&data_config, &pool).await {
+            Ok(report) => {
+                DatasetService::log_report(&report);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                error!("Failed to import projects: {}", e);
+                std::process::exit(1);
+            }
         }
-    };
+    }
 
     // Load project data
-    let projects = match load_projects_from_json().await {
-        Ok(projects) => {
-            info!(" Loaded {} projects from JSON", projects.len());
-            projects
+    let (dataset, refused) = match DatasetService::load_dataset(&data_config, Some(&pool)).await {
+        Ok(dataset) => {
+            DatasetService::log_report(&dataset.report);
+            DatasetService::archive_snapshot(&data_config, &dataset).await;
+            (dataset, None)
         }
-        Err(e) => {
-            error!("Failed to load projects: {}", e);
-            vec![] // Continue with empty project list
+        Err(LoadError { error, refused }) => {
+            if let Some(refused) = &refused {
+                DatasetService::log_report(&refused.report);
+            }
+            error!("Failed to load projects: {}", error);
+            // Continue with empty project list; the watcher or an admin reload can fill it later
+            let report = DatasetLoadReport::new(data_config.projects_file.display().to_string());
+            (Dataset::new(vec![], report, SnapshotService::checksum(b"")), refused)
         }
     };
 
     // Create app state
-    let state = AppState::new(pool.clone(), Arc::new(projects));
+    let state = AppState::new(pool.clone(), data_config, dataset);
+    if let Some(refused) = refused {
+        state.refuse_load(refused);
+    }
+
+    // Reload the dataset whenever the pipeline regenerates it
+    DatasetService::spawn_watcher(state.clone());
 
     // Create routes
-    let routes = create_routes().with_state(state);
+    let routes = create_routes()
+        .merge(create_user_routes(pool.clone()))
+        .merge(create_admin_routes(pool.clone()))
+        .with_state(state);
 
     // Apply middleware
     let cors_layer = if app_config.environment.is_production() {
@@ -102,82 +162,17 @@ async fn main() {
     }
 }
 
-/// Load projects from JSON file
-async fn load_projects_from_json() -> Result<Vec<shared::Project>, Box<dyn std::error::Error>> {
-    // Try workspace root first (for cargo run from workspace root)
-    let workspace_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
-        .parent()
-        .unwrap()
-        .parent()
-        .unwrap()
-        .parent()
-        .unwrap()
-        .join("data/raw/all_projects_structured.json");
-
-    info!("Trying workspace path: {:?}", workspace_path);
-
-    if workspace_path.exists() {
-        let contents = tokio::fs::read_to_string(&workspace_path).await?;
-        let data: serde_json::Value = serde_json::from_str(&contents)?;
-
-        // Extract projects from nested structure
-        let mut all_projects = Vec::new();
-        if let Some(by_category) = data.get("by_category").and_then(|v| v.as_object()) {
-            for (_category, projects) in by_category {
-                if let Some(project_array) = projects.as_array() {
-                    for project in project_array {
-                        if let Ok(p) = serde_json::from_value::<shared::Project>(project.clone()) {
-                            all_projects.push(p);
-                        }
-                    }
-                }
-            }
-        }
-        return Ok(all_projects);
-    }
-
-    // Try current directory (for cargo run from backend directory)
-    let cwd = std::env::current_dir()?;
-    let cwd_path = cwd.join("data/raw/all_projects_structured.json");
-
-    if cwd_path.exists() {
-        let contents = tokio::fs::read_to_string(&cwd_path).await?;
-        let data: serde_json::Value = serde_json::from_str(&contents)?;
-
-        let mut all_projects = Vec::new();
-        if let Some(by_category) = data.get("by_category").and_then(|v| v.as_object()) {
-            for (_category, projects) in by_category {
-                if let Some(project_array) = projects.as_array() {
-                    for project in project_array {
-                        if let Ok(p) = serde_json::from_value::<shared::Project>(project.clone()) {
-                            all_projects.push(p);
-                        }
-                    }
-                }
-            }
+/// Connect to PostgreSQL, exiting when the database is unreachable
+async fn connect(db_config: &DbConfig) -> PgPool {
+    match create_pool(db_config).await {
+        Ok(pool) => {
+            info!(" Database connection established");
+            pool
         }
-        return Ok(all_projects);
-    }
-
-    // Try parent of current directory
-    let parent_path = cwd.parent()
-        .ok_or("No parent directory")?
-        .join("data/raw/all_projects_structured.json");
-
-    let contents = tokio::fs::read_to_string(&parent_path).await?;
-    let data: serde_json::Value = serde_json::from_str(&contents)?;
-
-    let mut all_projects = Vec::new();
-    if let Some(by_category) = data.get("by_category").and_then(|v| v.as_object()) {
-        for (_category, projects) in by_category {
-            if let Some(project_array) = projects.as_array() {
-                for project in project_array {
-                    if let Ok(p) = serde_json::from_value::<shared::Project>(project.clone()) {
-                        all_projects.push(p);
-                    }
-                }
-            }
+        Err(e) => {
+            error!("Failed to connect to database: {}", e);
+            error!("Make sure PostgreSQL is running and DATABASE_URL is set correctly");
+            std::process::exit(1);
         }
     }
-    Ok(all_projects)
 }
@@ -1,5 +1,7 @@
 mod user_repository;
 mod project_repository;
+mod saved_search_repository;
 
 pub use user_repository::*;
 pub use project_repository::*;
+pub use saved_search_repository::*;
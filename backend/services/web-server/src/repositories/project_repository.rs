@@ -1,9 +1,426 @@
-// Project repository placeholder
-// Projects are loaded from JSON file in main.rs
-// Can be migrated to database later if needed
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use lib_web::AppError;
+use shared::{
+    Chain, Country, IntegrationStatus, Program, Project, ProjectCategory, ProjectFilter, ProjectType, Quarter,
+    Region,
+};
+
+use crate::services::{parse_rounds, PaginationService, SortField, SortSpec};
+
+const PROJECT_COLUMNS: &str = r#"
+    slug, title, project_type, company, country, description, category, total_awarded,
+    programs, rounds, quarters, integration_status, open_source, website, github, soroban,
+    other_chains, regions, traction, tags, funding_details, status, announcement_link
+"#;
+
+#[derive(Debug, sqlx::FromRow)]
+struct ProjectRow {
+    slug: String,
+    title: String,
+    project_type: Option<String>,
+    company: Option<String>,
+    country: Option<String>,
+    description: Option<String>,
+    category: Option<String>,
+    total_awarded: Option<f64>,
+    programs: Option<String>,
+    rounds: Option<String>,
+    quarters: Option<String>,
+    integration_status: Option<String>,
+    open_source: Option<String>,
+    website: Option<String>,
+    github: Option<String>,
+    soroban: Option<bool>,
+    other_chains: Option<String>,
+    regions: Option<String>,
+    traction: Option<String>,
+    tags: Vec<String>,
+    funding_details: Option<String>,
+    status: Option<String>,
+    announcement_link: Option<String>,
+}
+
+impl From<ProjectRow> for Project {
+    fn from(row: ProjectRow) -> Self {
+        Self {
+            id: row.slug,
+            title: row.title,
+            project_type: row.project_type,
+            company: row.company,
+            country: row.country,
+            description: row.description,
+            category: row.category,
+            total_awarded: row.total_awarded,
+            programs: row.programs,
+            rounds: row.rounds,
+            quarters: row.quarters,
+            integration_status: row.integration_status,
+            open_source: row.open_source,
+            website: row.website,
+            github: row.github,
+            soroban: row.soroban,
+            other_chains: row.other_chains,
+            regions: row.regions,
+            traction: row.traction,
+            tags: row.tags,
+            funding_details: row.funding_details,
+            status: row.status,
+            announcement_link: row.announcement_link,
+            parsed: Default::default(),
+        }
+    }
+}
+
+/// The columns the importer derives from a parsed project for filtering and sorting
+struct Normalized {
+    category: String,
+    project_type: Option<String>,
+    country_code: Option<&'static str>,
+    integration_stage: Option<String>,
+    is_open_source: bool,
+    is_multichain: bool,
+    chain_names: Vec<String>,
+    program_names: Vec<String>,
+    round_count: i32,
+    quarter_ordinals: Vec<i32>,
+    latest_quarter: Option<i32>,
+    sort_title: String,
+    sort_country: Option<String>,
+}
+
+impl Normalized {
+    fn new(project: &Project) -> Self {
+        Self {
+            category: project.get_category().as_str().to_string(),
+            project_type: project.parsed.project_type.map(|t| t.as_str().to_string()),
+            country_code: project.parsed.country.map(|c| c.alpha2),
+            integration_stage: project.parsed.integration_status.map(|s| s.as_str().to_string()),
+            is_open_source: project.is_open_source(),
+            is_multichain: project.is_multichain(),
+            chain_names: project.parsed.chains.iter().map(|c| c.as_str().to_lowercase()).collect(),
+            program_names: project.parsed.programs.iter().map(|p| p.as_str().to_string()).collect(),
+            round_count: project.parsed.rounds.len() as i32,
+            quarter_ordinals: project.parsed.quarters.iter().map(|q| q.ordinal() as i32).collect(),
+            latest_quarter: project.latest_quarter().map(|q| q.ordinal() as i32),
+            // The text keys `SortField::value` compares, so both orders agree
+            sort_title: project.title.trim().to_lowercase(),
+            sort_country: project.country_name().map(str::to_lowercase),
+        }
+    }
+}
+
+/// `%value%` for ILIKE, with the value's own wildcards escaped
+fn contains_pattern(value: &str) -> String {
+    format!("%{}%", value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"))
+}
+
+/// A list filter, or else its single-value form, the way `ProjectService::matches_filter` reads them
+fn one_or_many<'a>(many: &'a Option<Vec<String>>, one: &'a Option<String>) -> &'a [String] {
+    match (many, one) {
+        (Some(many), _) => many,
+        (None, Some(one)) => std::slice::from_ref(one),
+        (None, None) => &[],
+    }
+}
 
 pub struct ProjectRepository;
 
 impl ProjectRepository {
-    // Future: Database operations for projects
+    /// Replace the whole projects table with the given dataset in one transaction; the
+    /// projects must have been through `Project::parse_fields`
+    pub async fn replace_all(
+        pool: &PgPool,
+        projects: &[Project],
+    ) -> Result<usize, AppError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM projects")
+            .execute(&mut *tx)
+            .await?;
+
+        // Stay well below the Postgres bind parameter limit
+        for chunk in projects.chunks(500) {
+            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+                r#"
+                INSERT INTO projects (
+                    slug, title, project_type, company, country, description, category, total_awarded,
+                    programs, rounds, quarters, integration_status, open_source, website, github, soroban,
+                    other_chains, regions, traction, tags, funding_details, status, announcement_link,
+                    normalized_category, normalized_type, country_code, integration_stage, is_open_source,
+                    is_multichain, chain_names, program_names, round_count, quarter_ordinals, latest_quarter,
+                    sort_title, sort_country
+                )
+                "#,
+            );
+
+            let rows: Vec<(&Project, Normalized)> = chunk.iter().map(|p| (p, Normalized::new(p))).collect();
+            qb.push_values(rows, |mut row, (p, n)| {
+                row.push_bind(&p.id)
+                    .push_bind(&p.title)
+                    .push_bind(&p.project_type)
+                    .push_bind(&p.company)
+                    .push_bind(&p.country)
+                    .push_bind(&p.description)
+                    .push_bind(&p.category)
+                    .push_bind(p.total_awarded)
+                    .push_bind(&p.programs)
+                    .push_bind(&p.rounds)
+                    .push_bind(&p.quarters)
+                    .push_bind(&p.integration_status)
+                    .push_bind(&p.open_source)
+                    .push_bind(&p.website)
+                    .push_bind(&p.github)
+                    .push_bind(p.soroban)
+                    .push_bind(&p.other_chains)
+                    .push_bind(&p.regions)
+                    .push_bind(&p.traction)
+                    .push_bind(&p.tags)
+                    .push_bind(&p.funding_details)
+                    .push_bind(&p.status)
+                    .push_bind(&p.announcement_link)
+                    .push_bind(n.category)
+                    .push_bind(n.project_type)
+                    .push_bind(n.country_code)
+                    .push_bind(n.integration_stage)
+                    .push_bind(n.is_open_source)
+                    .push_bind(n.is_multichain)
+                    .push_bind(n.chain_names)
+                    .push_bind(n.program_names)
+                    .push_bind(n.round_count)
+                    .push_bind(n.quarter_ordinals)
+                    .push_bind(n.latest_quarter)
+                    .push_bind(n.sort_title)
+                    .push_bind(n.sort_country);
+            });
+
+            qb.build().execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(projects.len())
+    }
+
+    /// Load every project, in import order
+    pub async fn find_all(pool: &PgPool) -> Result<Vec<Project>, AppError> {
+        let rows = sqlx::query_as::<_, ProjectRow>(&format!(
+            "SELECT {} FROM projects ORDER BY id",
+            PROJECT_COLUMNS
+        ))
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Project::from).collect())
+    }
+
+    /// Find project by its stable id
+    pub async fn find_by_id(pool: &PgPool, id: &str) -> Result<Project, AppError> {
+        sqlx::query_as::<_, ProjectRow>(&format!(
+            "SELECT {} FROM projects WHERE slug = $1",
+            PROJECT_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .map(Project::from)
+        .ok_or_else(|| AppError::NotFound("Project not found".to_string()))
+    }
+
+    /// Whether `find_filtered_ids` can answer this listing: everything but a search, a `q`
+    /// query, a cursor, the enrichment-based filters and sorts, and relevance order
+    pub fn supports(filter: &ProjectFilter, spec: &SortSpec) -> bool {
+        let given = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+        !given(&filter.search_query)
+            && filter.q.is_none()
+            && !given(&filter.cursor)
+            && filter.has_twitter != Some(true)
+            && filter.has_discord != Some(true)
+            && spec.keys().iter().all(|(field, _)| Self::sort_column(*field).is_some())
+    }
+
+    /// Ids of one page of the filtered listing in `spec` order, then id, and the total match count
+    pub async fn find_filtered_ids(
+        pool: &PgPool,
+        filter: &ProjectFilter,
+        spec: &SortSpec,
+    ) -> Result<(Vec<String>, usize), AppError> {
+        let (page, per_page) = PaginationService::validate(filter.page, filter.per_page)?;
+
+        let mut count_qb: QueryBuilder<Postgres> = QueryBuilder::new("SELECT COUNT(*) FROM projects");
+        Self::push_filters(&mut count_qb, filter);
+        let total: i64 = count_qb.build_query_scalar().fetch_one(pool).await?;
+
+        let mut qb: QueryBuilder<Postgres> = QueryBuilder::new("SELECT slug FROM projects");
+        Self::push_filters(&mut qb, filter);
+
+        let mut order_by: Vec<String> = Vec::new();
+        for (field, descending) in spec.keys() {
+            let column = Self::sort_column(*field).ok_or_else(|| {
+                AppError::InternalError(format!("{:?} cannot be sorted on in SQL", field))
+            })?;
+            let direction = if *descending { "DESC" } else { "ASC" };
+            order_by.push(format!("{} {} NULLS LAST", column, direction));
+        }
+        order_by.push(r#"slug COLLATE "C""#.to_string());
+        qb.push(" ORDER BY ").push(order_by.join(", "));
+        qb.push(" LIMIT ").push_bind(per_page as i64);
+        qb.push(" OFFSET ").push_bind(((page - 1) * per_page) as i64);
+
+        let ids: Vec<String> = qb.build_query_scalar().fetch_all(pool).await?;
+
+        Ok((ids, total as usize))
+    }
+
+    /// Column holding `SortField::value`; "C" collation compares text the way Rust does
+    fn sort_column(field: SortField) -> Option<&'static str> {
+        match field {
+            SortField::Funding => Some("total_awarded"),
+            SortField::Rounds => Some("round_count"),
+            SortField::Quarter => Some("latest_quarter"),
+            SortField::Country => Some(r#"sort_country COLLATE "C""#),
+            SortField::Title => Some(r#"sort_title COLLATE "C""#),
+            SortField::Social | SortField::Health | SortField::Rank => None,
+        }
+    }
+
+    /// Append a WHERE clause matching `ProjectService::matches_filter` on the normalized columns,
+    /// for every filter `supports` accepts
+    fn push_filters(qb: &mut QueryBuilder<'_, Postgres>, filter: &ProjectFilter) {
+        qb.push(" WHERE TRUE");
+
+        let categories = one_or_many(&filter.categories, &filter.category);
+        if !categories.is_empty() {
+            qb.push(" AND (FALSE");
+            for category in categories {
+                match ProjectCategory::from_str(category) {
+                    ProjectCategory::Other => qb.push(" OR category ILIKE ").push_bind(contains_pattern(category)),
+                    known => qb.push(" OR normalized_category = ").push_bind(known.as_str().to_string()),
+                };
+            }
+            qb.push(")");
+        }
+
+        if let Some(project_type) = &filter.project_type {
+            match ProjectType::parse(project_type) {
+                Some(known) => qb.push(" AND normalized_type = ").push_bind(known.as_str().to_string()),
+                None => qb
+                    .push(" AND LOWER(project_type) = LOWER(")
+                    .push_bind(project_type.trim().to_string())
+                    .push(")"),
+            };
+        }
+
+        if let Some(min) = filter.min_funding {
+            qb.push(" AND COALESCE(total_awarded, 0) >= ").push_bind(min);
+        }
+        if let Some(max) = filter.max_funding {
+            qb.push(" AND COALESCE(total_awarded, 0) <= ").push_bind(max);
+        }
+
+        if let Some(tier) = &filter.funding_tier {
+            let range = match tier.as_str() {
+                "0-50k" => Some((f64::MIN, 50000.0)),
+                "50k-100k" => Some((50000.0, 100000.0)),
+                "100k-150k" => Some((100000.0, 150000.0)),
+                "150k+" => Some((150000.0, f64::MAX)),
+                _ => None,
+            };
+            if let Some((low, high)) = range {
+                qb.push(" AND COALESCE(total_awarded, 0) >= ").push_bind(low);
+                qb.push(" AND COALESCE(total_awarded, 0) < ").push_bind(high);
+            }
+        }
+
+        if filter.soroban_only == Some(true) {
+            qb.push(" AND COALESCE(soroban, FALSE)");
+        }
+        if filter.stellar_only == Some(true) {
+            qb.push(" AND NOT is_multichain");
+        }
+        if filter.has_github == Some(true) {
+            qb.push(" AND github IS NOT NULL");
+        }
+        if filter.has_mainnet == Some(true) {
+            qb.push(" AND integration_stage = ").push_bind(IntegrationStatus::Mainnet.as_str().to_string());
+        }
+        if filter.is_open_source == Some(true) {
+            qb.push(" AND is_open_source");
+        }
+
+        if let Some(chains) = filter.other_chains.as_ref().filter(|c| !c.is_empty()) {
+            let names: Vec<String> = chains.iter().map(|c| Chain::parse(c).as_str().to_lowercase()).collect();
+            qb.push(" AND chain_names && ").push_bind(names);
+        }
+
+        let countries = one_or_many(&filter.countries, &filter.country);
+        if !countries.is_empty() {
+            qb.push(" AND (FALSE");
+            for country in countries {
+                match Country::lookup(country) {
+                    Some(known) => qb.push(" OR country_code = ").push_bind(known.alpha2),
+                    None => qb.push(" OR country ILIKE ").push_bind(contains_pattern(country)),
+                };
+            }
+            qb.push(")");
+        }
+
+        let regions = one_or_many(&filter.regions, &filter.region);
+        if !regions.is_empty() {
+            qb.push(" AND (FALSE");
+            for region in regions {
+                match Region::parse(region) {
+                    Some(known) => {
+                        let codes: Vec<&str> =
+                            Country::all().iter().filter(|c| known.contains(c)).map(|c| c.alpha2).collect();
+                        qb.push(" OR country_code = ANY(").push_bind(codes).push(")")
+                    }
+                    None => qb.push(" OR regions ILIKE ").push_bind(contains_pattern(region)),
+                };
+            }
+            qb.push(")");
+        }
+
+        if let Some(programs) = filter.programs.as_ref().filter(|p| !p.is_empty()) {
+            let names: Vec<String> = programs.iter().map(|p| Program::parse(p).as_str().to_string()).collect();
+            qb.push(" AND program_names && ").push_bind(names);
+        }
+
+        if let Some(min_rounds) = filter.min_rounds {
+            qb.push(" AND round_count >= ").push_bind(min_rounds as i32);
+        }
+        if let Some((count, or_more)) = filter.rounds.as_deref().and_then(parse_rounds) {
+            qb.push(if or_more { " AND round_count >= " } else { " AND round_count = " })
+                .push_bind(count as i32);
+        }
+
+        if let Some(status) = &filter.status {
+            qb.push(" AND status ILIKE ").push_bind(contains_pattern(status));
+        }
+
+        if let Some(status) = &filter.integration_status {
+            match IntegrationStatus::parse(status) {
+                Some(known) => qb.push(" AND integration_stage = ").push_bind(known.as_str().to_string()),
+                None => qb.push(" AND integration_status ILIKE ").push_bind(contains_pattern(status.trim())),
+            };
+        }
+
+        if filter.has_website == Some(true) {
+            qb.push(" AND website IS NOT NULL");
+        }
+        if filter.has_traction == Some(true) {
+            qb.push(" AND traction IS NOT NULL");
+        }
+
+        let quarters = one_or_many(&filter.quarters, &filter.quarter);
+        if !quarters.is_empty() {
+            let ordinals: Vec<i32> =
+                quarters.iter().filter_map(|q| Quarter::parse(q)).map(|q| q.ordinal() as i32).collect();
+            qb.push(" AND quarter_ordinals && ").push_bind(ordinals);
+        }
+        if let Some(year) = filter.year.as_deref().and_then(|y| y.trim().parse::<u16>().ok()) {
+            qb.push(" AND EXISTS (SELECT 1 FROM UNNEST(quarter_ordinals) AS q WHERE q / 4 = ")
+                .push_bind(year as i32)
+                .push(")");
+        }
+    }
 }
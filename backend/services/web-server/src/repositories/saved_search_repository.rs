@@ -0,0 +1,181 @@
+use chrono::{DateTime, Utc};
+use sqlx::{types::Json, PgPool};
+use uuid::Uuid;
+use lib_utils::{new_uuid, now};
+use lib_web::AppError;
+use shared::{ProjectFilter, SavedSearch};
+
+const SAVED_SEARCH_COLUMNS: &str = r#"
+    id, name, filter, created_at, updated_at, last_run_at, result_count, result_delta, result_ids
+"#;
+
+#[derive(Debug, sqlx::FromRow)]
+struct SavedSearchRow {
+    id: Uuid,
+    name: String,
+    filter: Json<ProjectFilter>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    last_run_at: Option<DateTime<Utc>>,
+    result_count: Option<i32>,
+    result_delta: Option<i32>,
+    result_ids: Vec<String>,
+}
+
+impl SavedSearchRow {
+    /// The search, and the project ids it matched at its last run
+    fn into_parts(self) -> (SavedSearch, Vec<String>) {
+        let search = SavedSearch {
+            id: self.id,
+            name: self.name,
+            filter: self.filter.0,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            last_run_at: self.last_run_at,
+            result_count: self.result_count.map(|count| count.max(0) as usize),
+            result_delta: self.result_delta.map(i64::from),
+        };
+        (search, self.result_ids)
+    }
+}
+
+/// Unique-name violations surface as a readable error rather than a database error
+fn map_name_conflict(err: sqlx::Error, name: &str) -> AppError {
+    match &err {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            AppError::BadRequest(format!("A saved search named \"{}\" already exists", name))
+        }
+        _ => AppError::Database(err),
+    }
+}
+
+pub struct SavedSearchRepository;
+
+impl SavedSearchRepository {
+    /// A user's saved searches, most recently updated first
+    pub async fn list(pool: &PgPool, user_id: Uuid) -> Result<Vec<SavedSearch>, AppError> {
+        let rows = sqlx::query_as::<_, SavedSearchRow>(&format!(
+            "SELECT {} FROM saved_searches WHERE user_id = $1 ORDER BY updated_at DESC, name",
+            SAVED_SEARCH_COLUMNS
+        ))
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.into_parts().0).collect())
+    }
+
+    /// A saved search owned by the user, with the project ids of its last run
+    pub async fn find(
+        pool: &PgPool,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<(SavedSearch, Vec<String>), AppError> {
+        sqlx::query_as::<_, SavedSearchRow>(&format!(
+            "SELECT {} FROM saved_searches WHERE id = $1 AND user_id = $2",
+            SAVED_SEARCH_COLUMNS
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .map(SavedSearchRow::into_parts)
+        .ok_or_else(|| AppError::NotFound("Saved search not found".to_string()))
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        name: &str,
+        filter: &ProjectFilter,
+    ) -> Result<SavedSearch, AppError> {
+        let now = now();
+
+        sqlx::query_as::<_, SavedSearchRow>(&format!(
+            r#"
+            INSERT INTO saved_searches (id, user_id, name, filter, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING {}
+            "#,
+            SAVED_SEARCH_COLUMNS
+        ))
+        .bind(new_uuid())
+        .bind(user_id)
+        .bind(name)
+        .bind(Json(filter))
+        .bind(now)
+        .bind(now)
+        .fetch_one(pool)
+        .await
+        .map(|row| row.into_parts().0)
+        .map_err(|e| map_name_conflict(e, name))
+    }
+
+    pub async fn rename(
+        pool: &PgPool,
+        user_id: Uuid,
+        id: Uuid,
+        name: &str,
+    ) -> Result<SavedSearch, AppError> {
+        sqlx::query_as::<_, SavedSearchRow>(&format!(
+            r#"
+            UPDATE saved_searches
+            SET name = $1, updated_at = $2
+            WHERE id = $3 AND user_id = $4
+            RETURNING {}
+            "#,
+            SAVED_SEARCH_COLUMNS
+        ))
+        .bind(name)
+        .bind(now())
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| map_name_conflict(e, name))?
+        .map(|row| row.into_parts().0)
+        .ok_or_else(|| AppError::NotFound("Saved search not found".to_string()))
+    }
+
+    pub async fn delete(pool: &PgPool, user_id: Uuid, id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM saved_searches WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Saved search not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Store the outcome of a run
+    pub async fn record_run(
+        pool: &PgPool,
+        user_id: Uuid,
+        id: Uuid,
+        result_delta: Option<i64>,
+        result_ids: &[String],
+    ) -> Result<SavedSearch, AppError> {
+        sqlx::query_as::<_, SavedSearchRow>(&format!(
+            r#"
+            UPDATE saved_searches
+            SET last_run_at = $1, result_count = $2, result_delta = $3, result_ids = $4
+            WHERE id = $5 AND user_id = $6
+            RETURNING {}
+            "#,
+            SAVED_SEARCH_COLUMNS
+        ))
+        .bind(now())
+        .bind(result_ids.len() as i32)
+        .bind(result_delta.map(|delta| delta as i32))
+        .bind(result_ids)
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .map(|row| row.into_parts().0)
+        .ok_or_else(|| AppError::NotFound("Saved search not found".to_string()))
+    }
+}
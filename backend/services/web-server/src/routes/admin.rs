@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use lib_core::ProjectSource;
+use lib_web::{success, AppError};
+use serde::Deserialize;
+use shared::DatasetReports;
+use crate::{
+    extractors::PinnedDataset,
+    services::{DataQualityService, DatasetService},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct DataQualityParams {
+    /// `json` (default) or `markdown`
+    format: Option<String>,
+}
+
+pub async fn get_dataset_report(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    let reports = DatasetReports {
+        active: state.dataset().report.clone(),
+        refused: state.refused_load(),
+    };
+    Ok(success(reports, "Dataset load report retrieved successfully"))
+}
+
+pub async fn reload_dataset(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    match DatasetService::reload(&state).await? {
+        Some(report) => Ok(success(report, "Dataset reloaded successfully")),
+        None => Ok(success(state.dataset().report.clone(), "Dataset unchanged; kept the current snapshot")),
+    }
+}
+
+pub async fn import_projects(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = DatasetService::import_to_postgres(&state.data_config, &state.db).await?;
+    // Served from the table, the dataset has to follow it for listings to find the new rows
+    if state.data_config.source == ProjectSource::Postgres {
+        DatasetService::reload(&state).await?;
+    }
+    Ok(success(report, "Projects imported successfully"))
+}
+
+pub async fn get_data_quality(
+    PinnedDataset(dataset): PinnedDataset,
+    Query(params): Query<DataQualityParams>,
+) -> Result<Response, AppError> {
+    let report = DataQualityService::audit(&dataset);
+
+    match params.format.as_deref().unwrap_or("json") {
+        "json" => Ok(success(report, "Data quality report generated successfully").into_response()),
+        "markdown" | "md" => Ok((
+            [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+            DataQualityService::to_markdown(&report),
+        )
+            .into_response()),
+        other => Err(AppError::BadRequest(format!(
+            "Unknown format '{}', expected json or markdown",
+            other
+        ))),
+    }
+}
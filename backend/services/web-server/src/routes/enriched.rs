@@ -3,7 +3,7 @@ use axum::{
     Json,
 };
 use serde_json::{json, Value};
-use crate::AppState;
+use crate::{extractors::PinnedDataset, services::GeographicService, AppState};
 use lib_web::AppError;
 
 /// Helper function to wrap data in standard API response format
@@ -156,44 +156,26 @@ pub async fn get_seasonal_patterns(
 
 /// Get geographic analytics - country rankings
 pub async fn get_country_rankings(
-    State(_state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
 ) -> Result<Json<Value>, AppError> {
-    let data = tokio::fs::read_to_string("data/analytics/geographic/country_rankings.json")
-        .await
-        .map_err(|e| AppError::InternalError(format!("Failed to read country rankings: {}", e)))?;
-
-    let json: Value = serde_json::from_str(&data)
-        .map_err(|e| AppError::InternalError(format!("Failed to parse JSON: {}", e)))?;
-
-    Ok(Json(wrap_response(json, "Country rankings data retrieved successfully")))
+    let data = GeographicService::country_rankings(&dataset.projects);
+    Ok(Json(wrap_response(json!(data), "Country rankings data retrieved successfully")))
 }
 
 /// Get geographic analytics - regional analysis
 pub async fn get_regional_analysis(
-    State(_state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
 ) -> Result<Json<Value>, AppError> {
-    let data = tokio::fs::read_to_string("data/analytics/geographic/regional_analysis.json")
-        .await
-        .map_err(|e| AppError::InternalError(format!("Failed to read regional analysis: {}", e)))?;
-
-    let json: Value = serde_json::from_str(&data)
-        .map_err(|e| AppError::InternalError(format!("Failed to parse JSON: {}", e)))?;
-
-    Ok(Json(wrap_response(json, "Regional analysis data retrieved successfully")))
+    let data = GeographicService::regional_analysis(&dataset.projects);
+    Ok(Json(wrap_response(json!(data), "Regional analysis data retrieved successfully")))
 }
 
 /// Get geographic analytics - geographic gaps
 pub async fn get_geographic_gaps(
-    State(_state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
 ) -> Result<Json<Value>, AppError> {
-    let data = tokio::fs::read_to_string("data/analytics/geographic/geographic_gaps.json")
-        .await
-        .map_err(|e| AppError::InternalError(format!("Failed to read geographic gaps: {}", e)))?;
-
-    let json: Value = serde_json::from_str(&data)
-        .map_err(|e| AppError::InternalError(format!("Failed to parse JSON: {}", e)))?;
-
-    Ok(Json(wrap_response(json, "Geographic gaps data retrieved successfully")))
+    let data = GeographicService::geographic_gaps(&dataset.projects);
+    Ok(Json(wrap_response(json!(data), "Geographic gaps data retrieved successfully")))
 }
 
 /// Get success patterns
@@ -1,6 +1,10 @@
 use axum::{extract::State, response::IntoResponse};
 use lib_web::{success, AppError};
-use crate::{services::HandbookService, AppState};
+use crate::{
+    extractors::PinnedDataset,
+    services::{GeographicService, HandbookService},
+    AppState,
+};
 
 
 // Get full handbook data
@@ -29,9 +33,9 @@ pub async fn get_benchmarking(
 
 // Get geographic analysis
 pub async fn get_geographic_analysis(
-    State(_state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
 ) -> Result<impl IntoResponse, AppError> {
-    let data = HandbookService::get_geographic_analysis().await?;
+    let data = GeographicService::analysis(&dataset.projects);
     Ok(success(data, "Geographic analysis retrieved successfully"))
 }
 
@@ -69,8 +73,8 @@ pub async fn get_statistical_analysis(
 
 // Get all analytics combined
 pub async fn get_all_analytics(
-    State(_state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
 ) -> Result<impl IntoResponse, AppError> {
-    let data = HandbookService::get_all_analytics().await?;
+    let data = HandbookService::get_all_analytics(&dataset.projects).await?;
     Ok(success(data, "All analytics data retrieved successfully"))
 }
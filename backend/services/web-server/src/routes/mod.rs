@@ -5,8 +5,13 @@ mod predictor;
 mod health;
 mod enriched;
 mod handbook;
+mod admin;
+mod snapshots;
+mod saved_searches;
 
-use axum::{routing::{get, post}, Router};
+use axum::{middleware, routing::{get, patch, post}, Router};
+use lib_web::{admin_middleware, auth_middleware};
+use sqlx::PgPool;
 use crate::AppState;
 
 pub use health::*;
@@ -24,8 +29,14 @@ pub fn create_routes() -> Router<AppState> {
         // Project routes (protected)
         .route("/api/projects", get(projects::list_projects))
         .route("/api/projects/search", post(projects::search_projects))
+        .route("/api/projects/suggest", get(projects::suggest_projects))
         .route("/api/projects/{id}", get(projects::get_project))
         .route("/api/projects/{id}/enriched", get(projects::get_enriched_project))
+        .route("/api/projects/{id}/similar", get(projects::get_similar_projects))
+
+        // Dataset snapshot routes (protected)
+        .route("/api/snapshots", get(snapshots::list_snapshots))
+        .route("/api/snapshots/{from}/diff/{to}", get(snapshots::diff_snapshots))
 
         // Analytics routes (protected)
         .route("/api/analytics", get(analytics::get_dashboard))
@@ -85,3 +96,31 @@ pub fn create_routes() -> Router<AppState> {
         .route("/api/handbook/statistical", get(handbook::get_statistical_analysis))
         .route("/api/handbook/analytics", get(handbook::get_all_analytics))
 }
+
+/// Routes acting on the signed-in user's own data; `auth_middleware` resolves the session
+/// cookie to the `UserCtx` the handlers extract
+pub fn create_user_routes(db: PgPool) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/saved-searches",
+            get(saved_searches::list_saved_searches).post(saved_searches::create_saved_search),
+        )
+        .route(
+            "/api/saved-searches/{id}",
+            patch(saved_searches::rename_saved_search).delete(saved_searches::delete_saved_search),
+        )
+        .route("/api/saved-searches/{id}/run", post(saved_searches::run_saved_search))
+        .route_layer(middleware::from_fn_with_state(db, auth_middleware))
+}
+
+/// Dataset and import routes; `auth_middleware` resolves the session cookie and
+/// `admin_middleware` then requires `users.is_admin`
+pub fn create_admin_routes(db: PgPool) -> Router<AppState> {
+    Router::new()
+        .route("/api/admin/dataset/report", get(admin::get_dataset_report))
+        .route("/api/admin/dataset/reload", post(admin::reload_dataset))
+        .route("/api/admin/projects/import", post(admin::import_projects))
+        .route("/api/admin/data-quality", get(admin::get_data_quality))
+        .route_layer(middleware::from_fn(admin_middleware))
+        .route_layer(middleware::from_fn_with_state(db, auth_middleware))
+}
@@ -1,20 +1,20 @@
-use axum::{extract::State, response::IntoResponse, Json};
+use axum::{response::IntoResponse, Json};
 use lib_web::{success, AppError};
 use shared::{CompetitorSearchRequest, PredictionRequest};
-use crate::{services::PredictorService, AppState};
+use crate::{extractors::PinnedDataset, services::PredictorService};
 
 pub async fn predict_funding(
-    State(state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
     Json(req): Json<PredictionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let prediction = PredictorService::predict_funding(&state.projects, req).await?;
+    let prediction = PredictorService::predict_funding(&dataset.projects, req).await?;
     Ok(success(prediction, "Prediction completed successfully"))
 }
 
 pub async fn search_competitors(
-    State(state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
     Json(req): Json<CompetitorSearchRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let analysis = PredictorService::search_competitors(&state.projects, req).await?;
+    let analysis = PredictorService::search_competitors(&dataset, req).await?;
     Ok(success(analysis, "Competitor analysis completed successfully"))
 }
@@ -1,49 +1,105 @@
-use axum::{extract::{Path, Query, State}, response::IntoResponse, Json};
+use axum::{
+    extract::{Path, Query, State},
+    http::Uri,
+    response::{IntoResponse, Redirect, Response},
+    Json,
+};
 use lib_web::{success, AppError};
-use shared::{ProjectFilter, ProjectSearchRequest};
-use crate::{services::{ProjectService, EnrichedService}, AppState};
+use shared::{IncludeQuery, ProjectFilter, ProjectSearchRequest, SimilarQuery, SuggestQuery};
+use crate::{
+    extractors::{ListQuery, PinnedDataset},
+    services::{EnrichedService, FieldSelection, ProjectService, ProjectView},
+    AppState, Dataset,
+};
+
+/// Permanent redirect to the canonical URL when `id` is a title or retired id
+fn canonical_redirect(dataset: &Dataset, id: &str, suffix: &str, uri: &Uri) -> Option<Response> {
+    let canonical = dataset.canonical_id(id).filter(|canonical| *canonical != id)?;
+    let query = uri.query().map(|q| format!("?{}", q)).unwrap_or_default();
+
+    Some(Redirect::permanent(&format!("/api/projects/{}{}{}", canonical, suffix, query)).into_response())
+}
 
 pub async fn list_projects(
     State(state): State<AppState>,
-    Query(filter): Query<ProjectFilter>,
-) -> Result<impl IntoResponse, AppError> {
-    let response = ProjectService::filter_projects(&state.projects, filter).await?;
-    Ok(success(response, "Projects retrieved successfully"))
+    PinnedDataset(dataset): PinnedDataset,
+    ListQuery(filter): ListQuery<ProjectFilter>,
+    Query(include): Query<IncludeQuery>,
+) -> Result<Response, AppError> {
+    let fields = FieldSelection::parse(include.fields.as_deref())?;
+    let include = EnrichedService::parse_include(include.include.as_deref())?;
+
+    let mut response = ProjectService::filter_projects(&dataset, filter, state.projects_db(&dataset)).await?;
+    response.projects.iter_mut().for_each(|view| view.select(&fields));
+    EnrichedService::attach(&mut response.projects, &dataset.enrichment, include);
+
+    // Serialized here, while the projects are still borrowed from the pinned dataset
+    Ok(success(response, "Projects retrieved successfully").into_response())
 }
 
 pub async fn get_project(
-    State(state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
     Path(id): Path<String>,
-) -> Result<impl IntoResponse, AppError> {
-    let project = ProjectService::get_project(&state.projects, &id).await?;
-    Ok(success(project, "Project retrieved successfully"))
+    Query(include): Query<IncludeQuery>,
+    uri: Uri,
+) -> Result<Response, AppError> {
+    if let Some(redirect) = canonical_redirect(&dataset, &id, "", &uri) {
+        return Ok(redirect);
+    }
+    let fields = FieldSelection::parse(include.fields.as_deref())?;
+    let include = EnrichedService::parse_include(include.include.as_deref())?;
+
+    let mut project = ProjectView::new(ProjectService::get_project(&dataset, &id)?);
+    project.select(&fields);
+    EnrichedService::attach(std::slice::from_mut(&mut project), &dataset.enrichment, include);
+
+    Ok(success(project, "Project retrieved successfully").into_response())
 }
 
 pub async fn search_projects(
-    State(state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
     Json(req): Json<ProjectSearchRequest>,
+) -> Result<Response, AppError> {
+    let projects = ProjectService::search_projects(&dataset, req).await?;
+    Ok(success(projects, "Search completed successfully").into_response())
+}
+
+pub async fn suggest_projects(
+    PinnedDataset(dataset): PinnedDataset,
+    ListQuery(query): ListQuery<SuggestQuery>,
 ) -> Result<impl IntoResponse, AppError> {
-    let projects = ProjectService::search_projects(&state.projects, req).await?;
-    Ok(success(projects, "Search completed successfully"))
+    let suggestions = dataset.suggest.suggest(&query.q, query.limit);
+    Ok(success(suggestions, "Suggestions retrieved successfully"))
+}
+
+pub async fn get_similar_projects(
+    PinnedDataset(dataset): PinnedDataset,
+    Path(id): Path<String>,
+    ListQuery(query): ListQuery<SimilarQuery>,
+    uri: Uri,
+) -> Result<Response, AppError> {
+    if let Some(redirect) = canonical_redirect(&dataset, &id, "/similar", &uri) {
+        return Ok(redirect);
+    }
+
+    let similar = ProjectService::similar_projects(&dataset, &id, query.limit).await?;
+    Ok(success(similar, "Similar projects retrieved successfully").into_response())
 }
 
 pub async fn get_enriched_project(
-    State(state): State<AppState>,
+    PinnedDataset(dataset): PinnedDataset,
     Path(id): Path<String>,
-) -> Result<impl IntoResponse, AppError> {
-    // Get the base project
-    let project = ProjectService::get_project(&state.projects, &id).await?;
+    uri: Uri,
+) -> Result<Response, AppError> {
+    if let Some(redirect) = canonical_redirect(&dataset, &id, "/enriched", &uri) {
+        return Ok(redirect);
+    }
 
-    // Load enriched data
-    let website_metadata = EnrichedService::load_website_metadata().await?;
-    let social_links = EnrichedService::load_social_links().await?;
+    // Get the base project
+    let project = ProjectService::get_project(&dataset, &id)?;
 
-    // Get enriched data for this project
-    let enriched = EnrichedService::get_enriched_data(
-        &project.title,
-        &website_metadata,
-        &social_links,
-    ).await;
+    // Enriched data is joined once when the dataset loads
+    let enriched = EnrichedService::get_enriched_data(&project.id, &dataset.enrichment);
 
     // Combine project and enriched data
     let response = serde_json::json!({
@@ -51,5 +107,5 @@ pub async fn get_enriched_project(
         "enriched": enriched
     });
 
-    Ok(success(response, "Enriched project data retrieved successfully"))
+    Ok(success(response, "Enriched project data retrieved successfully").into_response())
 }
@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Extension, Json,
+};
+use lib_core::ctx::UserCtx;
+use lib_web::{success, AppError};
+use shared::{CreateSavedSearchRequest, RenameSavedSearchRequest, RunSavedSearchQuery};
+use uuid::Uuid;
+
+use crate::{
+    extractors::{ListQuery, PinnedDataset},
+    services::SavedSearchService,
+    AppState,
+};
+
+fn parse_id(id: &str) -> Result<Uuid, AppError> {
+    lib_utils::parse_uuid(id).map_err(|_| AppError::NotFound("Saved search not found".to_string()))
+}
+
+pub async fn list_saved_searches(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserCtx>,
+) -> Result<impl IntoResponse, AppError> {
+    let searches = SavedSearchService::list(&state.db, user.user_id).await?;
+    Ok(success(searches, "Saved searches retrieved successfully"))
+}
+
+pub async fn create_saved_search(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserCtx>,
+    Json(req): Json<CreateSavedSearchRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let search = SavedSearchService::create(&state.db, user.user_id, req).await?;
+    Ok(success(search, "Search saved successfully"))
+}
+
+pub async fn rename_saved_search(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserCtx>,
+    Path(id): Path<String>,
+    Json(req): Json<RenameSavedSearchRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let search = SavedSearchService::rename(&state.db, user.user_id, parse_id(&id)?, &req.name).await?;
+    Ok(success(search, "Saved search renamed successfully"))
+}
+
+pub async fn delete_saved_search(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserCtx>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    SavedSearchService::delete(&state.db, user.user_id, parse_id(&id)?).await?;
+    Ok(success(serde_json::json!({ "id": id }), "Saved search deleted successfully"))
+}
+
+pub async fn run_saved_search(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserCtx>,
+    PinnedDataset(dataset): PinnedDataset,
+    Path(id): Path<String>,
+    ListQuery(paging): ListQuery<RunSavedSearchQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let current = dataset.snapshot.id == state.dataset().snapshot.id;
+    let run = SavedSearchService::run(&state.db, &dataset, current, user.user_id, parse_id(&id)?, paging).await?;
+    Ok(success(run, "Saved search run successfully").into_response())
+}
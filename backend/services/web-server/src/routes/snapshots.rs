@@ -0,0 +1,21 @@
+use axum::{extract::{Path, State}, response::IntoResponse};
+use lib_web::{success, AppError};
+use crate::{services::SnapshotService, AppState};
+
+pub async fn list_snapshots(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    let snapshots = SnapshotService::list(&state).await?;
+    Ok(success(snapshots, "Snapshots retrieved successfully"))
+}
+
+pub async fn diff_snapshots(
+    State(state): State<AppState>,
+    Path((from, to)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    let from = SnapshotService::resolve(&state, &from).await?;
+    let to = SnapshotService::resolve(&state, &to).await?;
+
+    let diff = SnapshotService::diff(&from, &to);
+    Ok(success(diff, "Snapshot diff computed successfully"))
+}
@@ -1,5 +1,5 @@
 use shared::{
-    CategoryStats, DashboardResponse, DashboardStats, Project, TimelineData, TimelineResponse,
+    CategoryStats, DashboardResponse, DashboardStats, Project, TimelineData, TimelineQuery, TimelineResponse,
     CategoryBreakdownResponse, OpportunityBubble, OpportunityHeatmapResponse, HeatmapFilters,
     ProjectRecommendation, RecommendationRequest, RecommendationResponse,
     FundingCalculation, FundingCalculatorRequest, FundingCalculatorResponse, FundingRange, RoundPotential,
@@ -7,12 +7,19 @@ use shared::{
     ApplicationTimeline, TimelinePlannerRequest, TimelinePlannerResponse, TimelineRound, Milestone,
     CategoryDeepDive, CategoryDeepDiveResponse, SuccessPatterns, GrowthTrend,
     GapOpportunity, GapAnalysisResponse,
-    SuccessAnalysis, SuccessPatternRequest, SuccessPatternResponse,
+    SuccessAnalysis, SuccessPatternRequest, SuccessPatternResponse, TraitAnalysis,
     LiveDashboard, LiveDashboardResponse, TrendingCategory, RecentActivity, HotOpportunity, QuarterlyStats,
     ProposalTemplate, ProposalTemplateRequest, ProposalTemplateResponse, TemplateSection,
+    ProjectFilter, Quarter, ProjectCategory, FundingBucket,
 };
+use shared::models::analytics::TopProject;
 use lib_web::AppError;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::{
+    services::{awards, funding_tier, Enrichment, Granularity, ProjectService, ProjectTrait, TimelineService, FUNDING_TIERS},
+    Dataset,
+};
 
 pub struct AnalyticsService;
 
@@ -80,33 +87,17 @@ impl AnalyticsService {
         Ok(CategoryBreakdownResponse { categories })
     }
 
-    /// Get timeline data
+    /// Awards per quarter or year from the parsed award quarters of the projects matching
+    /// the filter, overall and per category
     pub async fn get_timeline(
-        projects: &[Project],
+        dataset: &Dataset,
+        filter: ProjectFilter,
+        query: TimelineQuery,
     ) -> Result<TimelineResponse, AppError> {
-        // Placeholder - would need actual date parsing from project data
-        let timeline = vec![
-            TimelineData {
-                period: "2023".to_string(),
-                project_count: projects.len() / 2,
-                total_funding: projects
-                    .iter()
-                    .filter_map(|p| p.get_funding_amount_numeric())
-                    .sum::<f64>()
-                    / 2.0,
-            },
-            TimelineData {
-                period: "2024".to_string(),
-                project_count: projects.len() / 2,
-                total_funding: projects
-                    .iter()
-                    .filter_map(|p| p.get_funding_amount_numeric())
-                    .sum::<f64>()
-                    / 2.0,
-            },
-        ];
+        let granularity = Granularity::parse(query.granularity.as_deref())?;
+        let projects = ProjectService::matching(dataset, &filter)?;
 
-        Ok(TimelineResponse { timeline })
+        Ok(TimelineService::timeline(&projects, granularity))
     }
 
     // Helper methods
@@ -126,6 +117,112 @@ impl AnalyticsService {
         }
     }
 
+    /// The projects of a category and its display name. Taxonomy names and aliases (`defi`)
+    /// match by taxonomy, `other` every uncategorized project and anything else the exact raw
+    /// category, ignoring case; no match is a 404.
+    fn resolve_category<'a>(projects: &'a [Project], category: &str) -> Result<(String, Vec<&'a Project>), AppError> {
+        let category = category.trim();
+        let (name, matching): (String, Vec<&Project>) = match ProjectCategory::from_str(category) {
+            ProjectCategory::Other if !category.eq_ignore_ascii_case("other") => (
+                category.to_string(),
+                projects
+                    .iter()
+                    .filter(|p| {
+                        p.get_category() == ProjectCategory::Other
+                            && p.category.as_deref().is_some_and(|c| c.trim().eq_ignore_ascii_case(category))
+                    })
+                    .collect(),
+            ),
+            known => (
+                known.as_str().to_string(),
+                projects.iter().filter(|p| p.get_category() == known).collect(),
+            ),
+        };
+        if matching.is_empty() {
+            return Err(AppError::NotFound(format!("No projects in category '{}'", category)));
+        }
+        Ok((name, matching))
+    }
+
+    /// Percentage change from `previous` to `current`; 100 when there was nothing before
+    fn growth_rate(previous: f64, current: f64) -> f64 {
+        if previous > 0.0 {
+            (current - previous) / previous * 100.0
+        } else if current > 0.0 {
+            100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// The period before the last, if any, and the last
+    fn last_two(timeline: &[TimelineData]) -> Option<(Option<&TimelineData>, &TimelineData)> {
+        let (current, earlier) = timeline.split_last()?;
+        Some((earlier.last(), current))
+    }
+
+    /// Funding over the four quarters up to `latest` against the four before
+    fn growth_trend(projects: &[&Project], latest: Option<Quarter>) -> GrowthTrend {
+        let Some(latest) = latest else {
+            return GrowthTrend {
+                trend: "stable".to_string(),
+                yoy_change: 0.0,
+                recent_activity: "No dated awards".to_string(),
+            };
+        };
+
+        let (mut recent, mut earlier) = ((0.0, 0), (0.0, 0));
+        for project in projects {
+            for (quarter, share) in awards(project) {
+                let period = match latest.ordinal().checked_sub(quarter.ordinal()) {
+                    Some(0..=3) => &mut recent,
+                    Some(4..=7) => &mut earlier,
+                    _ => continue,
+                };
+                period.0 += share;
+                period.1 += 1;
+            }
+        }
+
+        let yoy_change = Self::growth_rate(earlier.0, recent.0);
+        GrowthTrend {
+            trend: match yoy_change {
+                change if change > 10.0 => "growing",
+                change if change < -10.0 => "declining",
+                _ => "stable",
+            }
+            .to_string(),
+            yoy_change,
+            recent_activity: format!(
+                "{} awards in the four quarters to {}, {} in the four before",
+                recent.1,
+                latest.label(),
+                earlier.1
+            ),
+        }
+    }
+
+    /// The latest quarter of a quarterly timeline against its best one by funding
+    fn quarterly_stats(timeline: &[TimelineData]) -> QuarterlyStats {
+        let best = timeline.iter().max_by(|a, b| a.total_funding.total_cmp(&b.total_funding));
+        let current = timeline.last();
+        let funding_this_quarter = current.map_or(0.0, |q| q.total_funding);
+        let best_quarter_funding = best.map_or(0.0, |q| q.total_funding);
+
+        QuarterlyStats {
+            current_quarter: current.map(|q| q.period.clone()).unwrap_or_default(),
+            funding_this_quarter,
+            projects_this_quarter: current.map_or(0, |q| q.project_count),
+            comparison_to_best: if best_quarter_funding > 0.0 {
+                funding_this_quarter / best_quarter_funding * 100.0
+            } else {
+                0.0
+            },
+            best_quarter: best.map(|q| q.period.clone()).unwrap_or_default(),
+            best_quarter_funding,
+        }
+    }
+
     fn calculate_category_stats(projects: &[Project], total_funding: f64) -> Vec<CategoryStats> {
         let mut category_map: HashMap<String, (usize, f64)> = HashMap::new();
 
@@ -169,7 +266,7 @@ impl AnalyticsService {
         let mut type_map: HashMap<String, (usize, f64, f64)> = HashMap::new();
 
         for project in projects {
-            let project_type = project.project_type.clone().unwrap_or_else(|| "Other".to_string());
+            let project_type = project.type_name().unwrap_or("Other").to_string();
             let funding = project.get_funding_amount_numeric().unwrap_or(0.0);
 
             type_map
@@ -357,24 +454,25 @@ impl AnalyticsService {
 
     // Feature 4: Competitive Landscape
     pub async fn get_competitive_landscape(
-        projects: &[Project],
+        dataset: &Dataset,
         request: LandscapeRequest,
     ) -> Result<LandscapeResponse, AppError> {
+        let projects = &dataset.projects;
+        // Same semantics as the project listing's filter
+        let filter = ProjectFilter {
+            category: request.category,
+            min_funding: request.min_funding,
+            max_funding: request.max_funding,
+            soroban_only: Some(request.soroban_only),
+            has_mainnet: Some(request.mainnet_only),
+            year: request.year.map(|year| year.to_string()),
+            ..ProjectFilter::default()
+        };
+        ProjectService::validate_filter(&filter)?;
+
         let filtered_projects: Vec<&Project> = projects
             .iter()
-            .filter(|p| {
-                if let Some(ref cat) = request.category {
-                    if let Some(ref proj_cat) = p.category {
-                        if !proj_cat.contains(cat) {
-                            return false;
-                        }
-                    }
-                }
-                if request.soroban_only && !p.uses_soroban() {
-                    return false;
-                }
-                true
-            })
+            .filter(|p| ProjectService::matches_filter(p, &filter, &dataset.enrichment))
             .collect();
 
         let landscape_projects: Vec<LandscapeProject> = filtered_projects
@@ -383,12 +481,17 @@ impl AnalyticsService {
                 Some(LandscapeProject {
                     title: p.title.clone(),
                     category: p.category.clone().unwrap_or_default(),
-                    project_type: p.project_type.clone().unwrap_or_default(),
+                    project_type: p.type_name().unwrap_or_default().to_string(),
                     funding_amount: p.get_funding_amount_numeric()?,
                     soroban: p.uses_soroban(),
-                    mainnet: p.integration_status.as_deref() == Some("Mainnet"),
-                    year: None,
-                    integration_status: p.integration_status.clone().unwrap_or_default(),
+                    mainnet: p.is_mainnet(),
+                    year: p.first_quarter().map(|q| q.year() as i32),
+                    integration_status: p
+                        .parsed
+                        .integration_status
+                        .map(|s| s.as_str().to_string())
+                        .or_else(|| p.integration_status.clone())
+                        .unwrap_or_default(),
                 })
             })
             .collect();
@@ -464,13 +567,11 @@ impl AnalyticsService {
 
     // Feature 6: Category Deep Dive
     pub async fn get_category_deep_dive(
-        projects: &[Project],
+        dataset: &Dataset,
         category: String,
     ) -> Result<CategoryDeepDiveResponse, AppError> {
-        let category_projects: Vec<&Project> = projects
-            .iter()
-            .filter(|p| p.category.as_ref().map(|c| c.contains(&category)).unwrap_or(false))
-            .collect();
+        let (projects, enrichment) = (&dataset.projects, &dataset.enrichment);
+        let (category, category_projects) = Self::resolve_category(projects, &category)?;
 
         let funding_amounts: Vec<f64> = category_projects
             .iter()
@@ -483,13 +584,80 @@ impl AnalyticsService {
         } else {
             0.0
         };
+        let median_funding = Self::calculate_median(&funding_amounts);
 
-        let soroban_count = category_projects.iter().filter(|p| p.uses_soroban()).count();
-        let soroban_percentage = if !category_projects.is_empty() {
-            (soroban_count as f64 / category_projects.len() as f64) * 100.0
-        } else {
-            0.0
-        };
+        let soroban_percentage = ProjectTrait::Soroban.prevalence(&category_projects, enrichment) * 100.0;
+        let mainnet_percentage = ProjectTrait::Mainnet.prevalence(&category_projects, enrichment) * 100.0;
+
+        let total_rounds: usize = category_projects.iter().map(|p| p.parsed.rounds.len()).sum();
+        let avg_rounds = total_rounds as f64 / category_projects.len() as f64;
+
+        // Funding buckets use the `funding_tier` filter's tiers, unfunded projects in the lowest
+        let funding_distribution = FUNDING_TIERS
+            .iter()
+            .map(|tier| {
+                let count = category_projects
+                    .iter()
+                    .filter(|p| funding_tier(p.get_funding_amount_numeric().unwrap_or(0.0)) == *tier)
+                    .count();
+                FundingBucket {
+                    range: tier.to_string(),
+                    count,
+                    percentage: count as f64 / category_projects.len() as f64 * 100.0,
+                }
+            })
+            .collect();
+
+        let mut by_funding = category_projects.clone();
+        by_funding.sort_by(|a, b| {
+            b.get_funding_amount_numeric()
+                .unwrap_or(0.0)
+                .total_cmp(&a.get_funding_amount_numeric().unwrap_or(0.0))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let prevalence: HashMap<ProjectTrait, f64> = ProjectTrait::ALL
+            .iter()
+            .map(|t| (*t, t.prevalence(&category_projects, enrichment)))
+            .collect();
+        let top_projects = by_funding
+            .iter()
+            .take(5)
+            .map(|p| {
+                let funding = p.get_funding_amount_numeric().unwrap_or(0.0);
+                let mut characteristics: Vec<String> = ProjectTrait::ALL
+                    .iter()
+                    .filter(|t| t.has(p, enrichment) && prevalence[t] < 0.5)
+                    .map(|t| format!("{} ({:.0}% of the category)", t.label(), prevalence[t] * 100.0))
+                    .collect();
+                if median_funding > 0.0 {
+                    characteristics.push(format!("{:.1}x the category median funding", funding / median_funding));
+                }
+                TopProject { id: p.id.clone(), title: p.title.clone(), funding, characteristics }
+            })
+            .collect();
+
+        // Traits shared by the best-funded quarter of the category
+        let top_quarter = &by_funding[..by_funding.len().div_ceil(4)];
+        let mut common: Vec<(f64, String)> = ProjectTrait::ALL
+            .iter()
+            .map(|t| (t.prevalence(top_quarter, enrichment), t))
+            .filter(|(share, _)| *share >= 0.5)
+            .map(|(share, t)| {
+                let text = format!(
+                    "{}: {:.0}% of the top-funded quarter vs {:.0}% of the category",
+                    t.label(),
+                    share * 100.0,
+                    prevalence[t] * 100.0
+                );
+                (share, text)
+            })
+            .collect();
+        common.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let common_traits = common.into_iter().map(|(_, text)| text).collect();
+
+        let timeline_history = TimelineService::timeline(&category_projects, Granularity::Quarter).timeline;
+        let latest = projects.iter().filter_map(|p| p.latest_quarter()).max();
 
         Ok(CategoryDeepDiveResponse {
             deep_dive: CategoryDeepDive {
@@ -497,21 +665,17 @@ impl AnalyticsService {
                 total_projects: category_projects.len(),
                 total_funding,
                 avg_funding,
-                median_funding: Self::calculate_median(&funding_amounts),
-                funding_distribution: vec![],
-                timeline_history: vec![],
-                top_projects: vec![],
+                median_funding,
+                funding_distribution,
+                timeline_history,
+                top_projects,
                 success_patterns: SuccessPatterns {
                     soroban_percentage,
-                    mainnet_percentage: 50.0,
-                    avg_rounds: 1.5,
-                    common_traits: vec!["Stellar-native".to_string()],
-                },
-                growth_trend: GrowthTrend {
-                    trend: "growing".to_string(),
-                    yoy_change: 25.0,
-                    recent_activity: "High".to_string(),
+                    mainnet_percentage,
+                    avg_rounds,
+                    common_traits,
                 },
+                growth_trend: Self::growth_trend(&category_projects, latest),
             },
         })
     }
@@ -520,124 +684,393 @@ impl AnalyticsService {
     pub async fn get_gap_analysis(
         projects: &[Project],
     ) -> Result<GapAnalysisResponse, AppError> {
-        // Analyze gaps based on project types
-        let mut type_counts: HashMap<String, usize> = HashMap::new();
+        Ok(Self::gaps(projects))
+    }
+
+    /// Region of a gap-analysis cell: the continent of the project's country
+    fn gap_region(project: &Project) -> &'static str {
+        project.parsed.country.map_or("Unknown region", |country| country.continent().as_str())
+    }
+
+    /// Technology of a gap-analysis cell
+    fn gap_technology(project: &Project) -> &'static str {
+        match (project.uses_soroban(), project.is_multichain()) {
+            (true, true) => "Soroban + multichain",
+            (true, false) => "Soroban",
+            (false, true) => "Multichain",
+            (false, false) => "Stellar classic",
+        }
+    }
+
+    /// Mean of funding density against the overall average and openness against the
+    /// typical number of projects per occupied cell, both 0 to 1, so a cell with average
+    /// funding and a typical project count scores 0.5
+    fn opportunity_score(avg_funding: f64, overall_avg: f64, project_count: usize, typical_count: f64) -> f64 {
+        let density = if avg_funding + overall_avg > 0.0 {
+            avg_funding / (avg_funding + overall_avg)
+        } else {
+            0.0
+        };
+        let openness = typical_count / (typical_count + project_count as f64);
+        (density + openness) / 2.0
+    }
+
+    fn thousands(amount: f64) -> String {
+        format!("${:.0}K", amount / 1000.0)
+    }
+
+    /// Every category × region × technology cell with its funding statistics and score
+    fn gaps(projects: &[Project]) -> GapAnalysisResponse {
+        type Cell = (String, &'static str, &'static str);
 
+        let mut cells: BTreeMap<Cell, Vec<&Project>> = BTreeMap::new();
         for project in projects {
-            if let Some(ref proj_type) = project.project_type {
-                *type_counts.entry(proj_type.clone()).or_insert(0) += 1;
-            }
+            let key = (
+                project.get_category().as_str().to_string(),
+                Self::gap_region(project),
+                Self::gap_technology(project),
+            );
+            cells.entry(key).or_default().push(project);
+        }
+
+        let amounts = |projects: &[&Project]| -> Vec<f64> {
+            projects.iter().filter_map(|p| p.get_funding_amount_numeric()).collect()
+        };
+        let mean = |values: &[f64]| if values.is_empty() { 0.0 } else { values.iter().sum::<f64>() / values.len() as f64 };
+
+        let all: Vec<&Project> = projects.iter().collect();
+        let overall_avg = mean(&amounts(&all));
+        let typical_count = projects.len() as f64 / cells.len().max(1) as f64;
+
+        let mut category_amounts: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+        for ((category, _, _), members) in &cells {
+            category_amounts.entry(category.as_str()).or_default().extend(amounts(members));
         }
 
-        let opportunities: Vec<GapOpportunity> = type_counts
+        let mut opportunities: Vec<GapOpportunity> = cells
             .iter()
-            .filter(|(_, &count)| count < 5)
-            .map(|(category, &count)| {
-                let avg_funding = 150000.0 / (count as f64 + 1.0);
+            .map(|((category, region, technology), members)| {
+                let funding = amounts(members);
+                let avg_funding = mean(&funding);
+                let median_funding = Self::calculate_median(&funding);
+                let min_funding = funding.iter().copied().reduce(f64::min).unwrap_or(0.0);
+                let max_funding = funding.iter().copied().reduce(f64::max).unwrap_or(0.0);
+                let count = members.len();
+                let dense = avg_funding >= overall_avg;
+                let open = count as f64 <= typical_count;
+
+                let market_need = format!(
+                    "{} {} project{} from {} averaging {} (median {}, max {}), {:.1}x the {} average across all projects; occupied cells hold {:.1} projects on average",
+                    count,
+                    technology,
+                    if count == 1 { "" } else { "s" },
+                    region,
+                    Self::thousands(avg_funding),
+                    Self::thousands(median_funding),
+                    Self::thousands(max_funding),
+                    if overall_avg > 0.0 { avg_funding / overall_avg } else { 0.0 },
+                    Self::thousands(overall_avg),
+                    typical_count,
+                );
+                let strategy = match (dense, open) {
+                    (true, true) => "Well funded and uncrowded: enter with a focused product",
+                    (true, false) => "Well funded but crowded: differentiate clearly from existing projects",
+                    (false, true) => "Uncrowded but modestly funded: keep the scope lean",
+                    (false, false) => "Crowded and modestly funded: consider a neighbouring niche",
+                };
+
                 GapOpportunity {
                     category: category.clone(),
+                    region: region.to_string(),
+                    technology: technology.to_string(),
                     project_count: count,
                     avg_funding,
-                    max_funding: avg_funding * 1.5,
-                    opportunity_score: 100.0 - (count as f64 * 10.0),
-                    market_need: "High demand, low competition".to_string(),
-                    strategy: "Build differentiated solution".to_string(),
-                    estimated_funding_range: FundingRange {
-                        min: avg_funding * 0.5,
-                        max: avg_funding * 2.0,
-                    },
+                    median_funding,
+                    max_funding,
+                    opportunity_score: Self::opportunity_score(avg_funding, overall_avg, count, typical_count),
+                    market_need,
+                    strategy: strategy.to_string(),
+                    estimated_funding_range: FundingRange { min: min_funding, max: max_funding },
                 }
             })
             .collect();
 
-        let zero_competition: Vec<GapOpportunity> = opportunities
+        // Empty cells among the categories, known regions and technologies that do have projects,
+        // scored on their category's funding with no competition
+        let regions: BTreeSet<&str> = cells.keys().map(|(_, region, _)| *region).filter(|r| *r != "Unknown region").collect();
+        let technologies: BTreeSet<&str> = cells.keys().map(|(_, _, technology)| *technology).collect();
+        let mut zero_competition: Vec<GapOpportunity> = Vec::new();
+        for (category, funding) in &category_amounts {
+            let avg_funding = mean(funding);
+            for region in &regions {
+                for technology in &technologies {
+                    let key = (category.to_string(), *region, *technology);
+                    if cells.contains_key(&key) {
+                        continue;
+                    }
+                    zero_competition.push(GapOpportunity {
+                        category: category.to_string(),
+                        region: region.to_string(),
+                        technology: technology.to_string(),
+                        project_count: 0,
+                        avg_funding: 0.0,
+                        median_funding: 0.0,
+                        max_funding: 0.0,
+                        opportunity_score: Self::opportunity_score(avg_funding, overall_avg, 0, typical_count),
+                        market_need: format!(
+                            "No {} projects from {} yet; {} projects elsewhere average {}, {:.1}x the {} average across all projects",
+                            technology,
+                            region,
+                            category,
+                            Self::thousands(avg_funding),
+                            if overall_avg > 0.0 { avg_funding / overall_avg } else { 0.0 },
+                            Self::thousands(overall_avg),
+                        ),
+                        strategy: "Untested: validate demand before committing".to_string(),
+                        estimated_funding_range: FundingRange {
+                            min: funding.iter().copied().reduce(f64::min).unwrap_or(0.0),
+                            max: funding.iter().copied().reduce(f64::max).unwrap_or(0.0),
+                        },
+                    });
+                }
+            }
+        }
+
+        let by_score = |a: &GapOpportunity, b: &GapOpportunity| {
+            b.opportunity_score
+                .total_cmp(&a.opportunity_score)
+                .then(a.project_count.cmp(&b.project_count))
+                .then_with(|| (&a.category, &a.region, &a.technology).cmp(&(&b.category, &b.region, &b.technology)))
+        };
+        opportunities.sort_by(by_score);
+        zero_competition.sort_by(by_score);
+
+        let total_gaps = opportunities
             .iter()
-            .filter(|o| o.project_count == 0)
-            .cloned()
-            .collect();
+            .chain(&zero_competition)
+            .filter(|o| o.opportunity_score > 0.5)
+            .count();
+        zero_competition.truncate(10);
 
-        Ok(GapAnalysisResponse {
-            total_gaps: opportunities.len(),
+        GapAnalysisResponse {
             opportunities,
+            total_gaps,
             zero_competition,
-        })
+        }
     }
 
     // Feature 8: Success Pattern Analyzer
     pub async fn analyze_success_patterns(
-        projects: &[Project],
+        dataset: &Dataset,
         request: SuccessPatternRequest,
     ) -> Result<SuccessPatternResponse, AppError> {
-        let category_projects: Vec<&Project> = projects
+        let enrichment = &dataset.enrichment;
+        let your_traits = request
+            .your_traits
+            .iter()
+            .map(|name| ProjectTrait::parse(name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (category, mut category_projects) = Self::resolve_category(&dataset.projects, &request.category)?;
+        category_projects.sort_by(|a, b| {
+            b.get_funding_amount_numeric()
+                .unwrap_or(0.0)
+                .total_cmp(&a.get_funding_amount_numeric().unwrap_or(0.0))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        let top_decile = &category_projects[..category_projects.len().div_ceil(10)];
+
+        let mut common_traits: Vec<TraitAnalysis> = ProjectTrait::ALL
             .iter()
-            .filter(|p| p.category.as_ref().map(|c| c.contains(&request.category)).unwrap_or(false))
+            .map(|t| Self::trait_analysis(*t, &category_projects, top_decile, enrichment, your_traits.contains(t)))
             .collect();
+        common_traits.sort_by(|a, b| {
+            b.lift
+                .unwrap_or(f64::NEG_INFINITY)
+                .total_cmp(&a.lift.unwrap_or(f64::NEG_INFINITY))
+                .then(b.top_decile_percentage.total_cmp(&a.top_decile_percentage))
+        });
+
+        // What most of the top decile shares, and how much of it the request covers
+        let decile_traits: Vec<&TraitAnalysis> =
+            common_traits.iter().filter(|t| t.top_decile_percentage >= 50.0).collect();
+        let top_decile_trait_coverage = if decile_traits.is_empty() {
+            0.0
+        } else {
+            decile_traits.iter().filter(|t| t.you_have).count() as f64 / decile_traits.len() as f64 * 100.0
+        };
 
-        let soroban_correlation = category_projects.iter().filter(|p| p.uses_soroban()).count() as f64
-            / category_projects.len().max(1) as f64;
+        let mut recommendations: Vec<String> = decile_traits
+            .iter()
+            .filter(|t| !t.you_have)
+            .map(|t| {
+                format!(
+                    "{}: {:.0}% of top-decile projects have it vs {:.0}% of the category, and you do not",
+                    t.trait_name,
+                    t.top_decile_percentage,
+                    t.occurrence_percentage
+                )
+            })
+            .collect();
+        if recommendations.is_empty() && !decile_traits.is_empty() {
+            recommendations.push("Your traits cover everything most top-decile projects share".to_string());
+        }
+
+        let decile_funding: Vec<f64> = top_decile.iter().filter_map(|p| p.get_funding_amount_numeric()).collect();
 
         Ok(SuccessPatternResponse {
             analysis: SuccessAnalysis {
-                category: request.category,
-                common_traits: vec![],
+                category,
                 optimal_funding_range: FundingRange {
-                    min: 75000.0,
-                    max: 200000.0,
+                    min: decile_funding.iter().copied().reduce(f64::min).unwrap_or(0.0),
+                    max: decile_funding.iter().copied().reduce(f64::max).unwrap_or(0.0),
                 },
-                soroban_correlation,
-                mainnet_correlation: 0.6,
-                success_probability: 72.0,
-                recommendations: vec![
-                    "Focus on Stellar-native features".to_string(),
-                    "Target mainnet launch".to_string(),
-                ],
+                soroban_correlation: Self::trait_correlation(ProjectTrait::Soroban, &category_projects, enrichment),
+                mainnet_correlation: Self::trait_correlation(ProjectTrait::Mainnet, &category_projects, enrichment),
+                top_decile_trait_coverage,
+                common_traits,
+                recommendations,
             },
         })
     }
 
+    /// How a trait is spread across the category and its top decile, and what projects with
+    /// it were awarded against those without
+    fn trait_analysis(
+        project_trait: ProjectTrait,
+        projects: &[&Project],
+        top_decile: &[&Project],
+        enrichment: &Enrichment,
+        you_have: bool,
+    ) -> TraitAnalysis {
+        let (with, without): (Vec<&Project>, Vec<&Project>) =
+            projects.iter().copied().partition(|p| project_trait.has(p, enrichment));
+        let avg = |group: &[&Project]| {
+            let funding: Vec<f64> = group.iter().filter_map(|p| p.get_funding_amount_numeric()).collect();
+            (!funding.is_empty()).then(|| funding.iter().sum::<f64>() / funding.len() as f64)
+        };
+        let (avg_with, avg_without) = (avg(&with), avg(&without));
+        let lift = match (avg_with, avg_without) {
+            (Some(with), Some(without)) if without > 0.0 => Some(with / without),
+            _ => None,
+        };
+
+        let occurrence_percentage = project_trait.prevalence(projects, enrichment) * 100.0;
+        let top_decile_percentage = project_trait.prevalence(top_decile, enrichment) * 100.0;
+        let description = match lift {
+            Some(lift) => format!(
+                "{} of {} projects ({:.0}%) average {} against {} without it, {:.2}x; {:.0}% of the top decile",
+                with.len(),
+                projects.len(),
+                occurrence_percentage,
+                Self::thousands(avg_with.unwrap_or(0.0)),
+                Self::thousands(avg_without.unwrap_or(0.0)),
+                lift,
+                top_decile_percentage
+            ),
+            None => format!(
+                "{} of {} projects ({:.0}%); too few on one side to compare funding",
+                with.len(),
+                projects.len(),
+                occurrence_percentage
+            ),
+        };
+
+        TraitAnalysis {
+            trait_name: project_trait.label().to_string(),
+            trait_key: project_trait.key().to_string(),
+            occurrence_percentage,
+            avg_funding_with_trait: avg_with.unwrap_or(0.0),
+            avg_funding_without_trait: avg_without.unwrap_or(0.0),
+            lift,
+            sample_size: with.len(),
+            sample_size_without: without.len(),
+            top_decile_percentage,
+            you_have,
+            description,
+        }
+    }
+
+    /// Pearson correlation between having the trait and funding, over the funded projects;
+    /// 0 when either does not vary
+    fn trait_correlation(project_trait: ProjectTrait, projects: &[&Project], enrichment: &Enrichment) -> f64 {
+        let points: Vec<(f64, f64)> = projects
+            .iter()
+            .filter_map(|p| {
+                let funding = p.get_funding_amount_numeric()?;
+                Some((if project_trait.has(p, enrichment) { 1.0 } else { 0.0 }, funding))
+            })
+            .collect();
+        if points.is_empty() {
+            return 0.0;
+        }
+
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let (mut covariance, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+        for (x, y) in &points {
+            covariance += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x).powi(2);
+            var_y += (y - mean_y).powi(2);
+        }
+        if var_x == 0.0 || var_y == 0.0 {
+            0.0
+        } else {
+            covariance / (var_x * var_y).sqrt()
+        }
+    }
+
     // Feature 9: Live Dashboard
     pub async fn get_live_dashboard(
         projects: &[Project],
     ) -> Result<LiveDashboardResponse, AppError> {
         let dashboard_response = Self::get_dashboard(projects).await?;
 
-        // Calculate trending categories from actual data
-        let mut category_map: HashMap<String, (usize, f64)> = HashMap::new();
-        for project in projects {
-            let cat = project.category.clone().unwrap_or_else(|| "Other".to_string());
-            let funding = project.get_funding_amount_numeric().unwrap_or(0.0);
-            category_map.entry(cat).and_modify(|(count, total)| {
-                *count += 1;
-                *total += funding;
-            }).or_insert((1, funding));
-        }
+        // The current quarter is the latest one with awards; categories trend by their
+        // change in funding since the quarter before
+        let refs: Vec<&Project> = projects.iter().collect();
+        let quarterly = TimelineService::timeline(&refs, Granularity::Quarter);
+        let quarterly_stats = Self::quarterly_stats(&quarterly.timeline);
 
-        let mut trending: Vec<TrendingCategory> = category_map
+        let mut trending: Vec<TrendingCategory> = quarterly
+            .categories
             .iter()
-            .map(|(cat, (count, total))| TrendingCategory {
-                category: cat.clone(),
-                growth_rate: (*count as f64 / projects.len() as f64) * 100.0,
-                recent_funding: *total,
-                project_velocity: *count as f64,
+            .filter_map(|series| {
+                let (previous, current) = Self::last_two(&series.timeline)?;
+                (current.total_funding > 0.0 || previous.is_some_and(|p| p.total_funding > 0.0)).then(|| {
+                    TrendingCategory {
+                        category: series.category.clone(),
+                        growth_rate: Self::growth_rate(previous.map_or(0.0, |p| p.total_funding), current.total_funding),
+                        recent_funding: current.total_funding,
+                        project_velocity: current.project_count as f64,
+                    }
+                })
             })
             .collect();
-        trending.sort_by(|a, b| b.recent_funding.partial_cmp(&a.recent_funding).unwrap());
+        trending.sort_by(|a, b| {
+            b.growth_rate
+                .total_cmp(&a.growth_rate)
+                .then(b.recent_funding.total_cmp(&a.recent_funding))
+                .then_with(|| a.category.cmp(&b.category))
+        });
         let trending_categories = trending.into_iter().take(5).collect();
 
-        // Get recent activity from latest projects
-        let mut recent: Vec<RecentActivity> = projects
+        // Recent activity: the most recently awarded projects, largest awards first within a quarter
+        let mut recent: Vec<(Quarter, RecentActivity)> = projects
             .iter()
             .filter_map(|p| {
-                Some(RecentActivity {
+                let quarter = p.latest_quarter()?;
+                Some((quarter, RecentActivity {
                     project_title: p.title.clone(),
                     category: p.category.clone()?,
                     funding: p.get_funding_amount_numeric()?,
-                    date: p.quarters.clone().unwrap_or_else(|| "Recent".to_string()),
-                })
+                    date: quarter.label(),
+                }))
             })
             .collect();
-        recent.sort_by(|a, b| b.funding.partial_cmp(&a.funding).unwrap());
-        let recent_activity = recent.into_iter().take(10).collect();
+        recent.sort_by(|(qa, a), (qb, b)| qb.cmp(qa).then(b.funding.total_cmp(&a.funding)));
+        let recent_activity = recent.into_iter().take(10).map(|(_, activity)| activity).collect();
 
         // Generate hot opportunities
         let gap_response = Self::get_gap_analysis(projects).await?;
@@ -645,7 +1078,7 @@ impl AnalyticsService {
             .into_iter()
             .take(5)
             .map(|opp| HotOpportunity {
-                category: opp.category,
+                category: format!("{} · {} · {}", opp.category, opp.region, opp.technology),
                 reason: opp.market_need,
                 potential_funding: opp.avg_funding,
             })
@@ -657,12 +1090,7 @@ impl AnalyticsService {
                 trending_categories,
                 recent_activity,
                 hot_opportunities,
-                quarterly_stats: QuarterlyStats {
-                    current_quarter: "Q4 2024".to_string(),
-                    funding_this_quarter: 5000000.0,
-                    projects_this_quarter: 50,
-                    comparison_to_best: 85.0,
-                },
+                quarterly_stats,
             },
         })
     }
@@ -713,3 +1141,141 @@ impl AnalyticsService {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn period(period: &str, project_count: usize, total_funding: f64) -> TimelineData {
+        TimelineData {
+            period: period.to_string(),
+            project_count,
+            total_funding,
+            new_projects: 0,
+            cumulative_projects: 0,
+            cumulative_funding: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_quarterly_stats_compare_latest_to_best_quarter() {
+        let timeline = [period("Q1 '24", 4, 200.0), period("Q2 '24", 6, 400.0), period("Q3 '24", 3, 100.0)];
+        let stats = AnalyticsService::quarterly_stats(&timeline);
+        assert_eq!(stats.current_quarter, "Q3 '24");
+        assert_eq!(stats.projects_this_quarter, 3);
+        assert_eq!(stats.best_quarter, "Q2 '24");
+        assert_eq!(stats.comparison_to_best, 25.0);
+
+        assert_eq!(AnalyticsService::growth_rate(400.0, 100.0), -75.0);
+        assert_eq!(AnalyticsService::growth_rate(0.0, 100.0), 100.0);
+        assert_eq!(AnalyticsService::quarterly_stats(&[]).comparison_to_best, 0.0);
+    }
+
+    #[test]
+    fn test_growth_trend_compares_last_four_quarters() {
+        let project = |funding: f64, quarters: &str| {
+            test_support::project(json!({
+                "title": quarters,
+                "total_awarded": funding,
+                "quarters": quarters,
+            }))
+        };
+        let projects = [project(100.0, "Q1 '23"), project(300.0, "Q2 '24, Q4 '24"), project(50.0, "Q1 '22")];
+        let refs: Vec<&Project> = projects.iter().collect();
+
+        let trend = AnalyticsService::growth_trend(&refs, Quarter::parse("Q4 '24"));
+        assert_eq!(trend.yoy_change, 200.0);
+        assert_eq!(trend.trend, "growing");
+        assert!(trend.recent_activity.starts_with("2 awards in the four quarters to Q4 '24, 1 in"));
+
+        let stale = AnalyticsService::growth_trend(&refs, Quarter::parse("Q4 '25"));
+        assert_eq!(stale.trend, "declining");
+    }
+
+    #[test]
+    fn test_gaps_score_cells_by_funding_density_and_competition() {
+        let project = |title: &str, country: &str, soroban: bool, funding: f64| {
+            test_support::project(json!({
+                "title": title,
+                "category": "Applications",
+                "country": country,
+                "soroban": soroban,
+                "total_awarded": funding,
+            }))
+        };
+        let projects = [
+            project("a", "Kenya", true, 300.0),
+            project("b", "Germany", false, 50.0),
+            project("c", "Germany", false, 100.0),
+            project("d", "Germany", false, 150.0),
+        ];
+
+        let gaps = AnalyticsService::gaps(&projects);
+        let best = &gaps.opportunities[0];
+        assert_eq!((best.region.as_str(), best.technology.as_str()), ("Africa", "Soroban"));
+        assert_eq!(best.project_count, 1);
+        assert_eq!(best.avg_funding, 300.0);
+        // density 300 / (300 + 150), openness 2 / (2 + 1)
+        assert!((best.opportunity_score - 2.0 / 3.0).abs() < 1e-9);
+        assert!(best.market_need.starts_with("1 Soroban project from Africa"));
+
+        let crowded = &gaps.opportunities[1];
+        assert_eq!(crowded.median_funding, 100.0);
+        assert_eq!((crowded.estimated_funding_range.min, crowded.estimated_funding_range.max), (50.0, 150.0));
+
+        let empty: Vec<(&str, &str)> =
+            gaps.zero_competition.iter().map(|o| (o.region.as_str(), o.technology.as_str())).collect();
+        assert_eq!(empty, [("Africa", "Stellar classic"), ("Europe", "Soroban")]);
+        assert_eq!(gaps.total_gaps, 3);
+    }
+
+    #[test]
+    fn test_trait_analysis_compares_funding_with_and_without() {
+        let project = |title: &str, soroban: bool, funding: f64| {
+            test_support::project(json!({
+                "title": title,
+                "soroban": soroban,
+                "total_awarded": funding,
+            }))
+        };
+        let projects = [project("a", true, 300.0), project("b", true, 100.0), project("c", false, 100.0)];
+        let refs: Vec<&Project> = projects.iter().collect();
+        let enrichment = Enrichment::default();
+
+        let soroban = AnalyticsService::trait_analysis(ProjectTrait::Soroban, &refs, &refs[..1], &enrichment, true);
+        assert_eq!((soroban.sample_size, soroban.sample_size_without), (2, 1));
+        assert_eq!(soroban.avg_funding_with_trait, 200.0);
+        assert_eq!(soroban.avg_funding_without_trait, 100.0);
+        assert_eq!(soroban.lift, Some(2.0));
+        assert_eq!(soroban.top_decile_percentage, 100.0);
+
+        let docs = AnalyticsService::trait_analysis(ProjectTrait::Docs, &refs, &refs[..1], &enrichment, false);
+        assert_eq!((docs.sample_size, docs.sample_size_without), (0, 3));
+        assert_eq!(docs.lift, None);
+
+        let correlation = AnalyticsService::trait_correlation(ProjectTrait::Soroban, &refs, &enrichment);
+        assert!((correlation - 0.5).abs() < 1e-9);
+        assert_eq!(ProjectTrait::parse("Open Source").unwrap(), ProjectTrait::OpenSource);
+        assert!(ProjectTrait::parse("viral").is_err());
+    }
+
+    #[test]
+    fn test_resolve_category_matches_taxonomy_other_and_exact_names() {
+        let project = |title: &str, category: &str| {
+            test_support::project(json!({
+                "title": title,
+                "category": category,
+            }))
+        };
+        let projects = [project("a", "Financial Protocols"), project("b", "Tooling"), project("c", "Wallet Tooling")];
+
+        let (name, defi) = AnalyticsService::resolve_category(&projects, "defi").unwrap();
+        assert_eq!((name.as_str(), defi.len()), ("Financial Protocols", 1));
+        let (name, tooling) = AnalyticsService::resolve_category(&projects, " tooling ").unwrap();
+        assert_eq!((name.as_str(), tooling[0].title.as_str(), tooling.len()), ("tooling", "b", 1));
+        assert_eq!(AnalyticsService::resolve_category(&projects, "Other").unwrap().1.len(), 2);
+        assert!(AnalyticsService::resolve_category(&projects, "Tool").is_err());
+    }
+}
@@ -0,0 +1,414 @@
+use chrono::Utc;
+use shared::{
+    DataQualityReport, DuplicateGroup, FieldCompleteness, FundingOutlier, MissingEnrichment,
+    ParsedFields, Project, Quarter, SpellingGroup, SpellingVariant, UnparseableValue,
+};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use crate::Dataset;
+
+/// Tukey fence multiplier; values beyond Q1 - k*IQR or Q3 + k*IQR are flagged
+const OUTLIER_IQR_MULTIPLIER: f64 = 1.5;
+
+/// A column name and a check for whether a project leaves it empty
+type ColumnCheck = (&'static str, fn(&Project) -> bool);
+
+pub struct DataQualityService;
+
+impl DataQualityService {
+    /// Audit a loaded dataset for missing, duplicated, inconsistent and unjoinable data
+    pub fn audit(dataset: &Dataset) -> DataQualityReport {
+        let projects = &dataset.projects;
+
+        let missing_fields = Self::missing_fields(projects);
+        let duplicate_titles = Self::duplicates(projects, |p| Some(p.title.as_str()));
+        let duplicate_companies = Self::duplicates(projects, |p| p.company.as_deref());
+        let country_spellings = Self::country_spellings(projects);
+        let funding_outliers = Self::funding_outliers(projects);
+        let unparseable_quarters = Self::unparseable_quarters(projects);
+        let (missing_enrichment, empty_enrichment_sources) = Self::missing_enrichment(dataset);
+
+        let issue_count = missing_fields.iter().map(|f| f.missing).sum::<usize>()
+            + duplicate_titles.len()
+            + duplicate_companies.len()
+            + country_spellings.len()
+            + funding_outliers.len()
+            + unparseable_quarters.len()
+            + missing_enrichment.len();
+
+        DataQualityReport {
+            snapshot_id: dataset.snapshot.id.clone(),
+            generated_at: Utc::now(),
+            project_count: projects.len(),
+            issue_count,
+            missing_fields,
+            duplicate_titles,
+            duplicate_companies,
+            country_spellings,
+            funding_outliers,
+            unparseable_quarters,
+            missing_enrichment,
+            empty_enrichment_sources,
+        }
+    }
+
+    /// Columns with at least one empty value, most incomplete first
+    fn missing_fields(projects: &[Project]) -> Vec<FieldCompleteness> {
+        fn blank(value: &Option<String>) -> bool {
+            value.as_deref().is_none_or(ParsedFields::is_placeholder)
+        }
+
+        let columns: [ColumnCheck; 17] = [
+            ("type", |p| blank(&p.project_type)),
+            ("company", |p| blank(&p.company)),
+            ("country", |p| blank(&p.country)),
+            ("description", |p| blank(&p.description)),
+            ("category", |p| blank(&p.category)),
+            ("total_awarded", |p| p.total_awarded.is_none()),
+            ("programs", |p| blank(&p.programs)),
+            ("rounds", |p| blank(&p.rounds)),
+            ("quarters", |p| blank(&p.quarters)),
+            ("integration_status", |p| blank(&p.integration_status)),
+            ("open_source", |p| blank(&p.open_source)),
+            ("website", |p| blank(&p.website)),
+            ("github", |p| blank(&p.github)),
+            ("soroban", |p| p.soroban.is_none()),
+            ("other_chains", |p| blank(&p.other_chains)),
+            ("regions", |p| blank(&p.regions)),
+            ("traction", |p| blank(&p.traction)),
+        ];
+
+        let mut missing: Vec<FieldCompleteness> = columns
+            .iter()
+            .map(|(field, is_missing)| {
+                let count = projects.iter().filter(|p| is_missing(p)).count();
+                FieldCompleteness {
+                    field: field.to_string(),
+                    missing: count,
+                    missing_percentage: count as f64 / projects.len().max(1) as f64 * 100.0,
+                }
+            })
+            .filter(|f| f.missing > 0)
+            .collect();
+        missing.sort_by(|a, b| b.missing.cmp(&a.missing).then_with(|| a.field.cmp(&b.field)));
+
+        missing
+    }
+
+    /// Groups of projects whose value is equal ignoring case and surrounding whitespace
+    fn duplicates(projects: &[Project], value: impl Fn(&Project) -> Option<&str>) -> Vec<DuplicateGroup> {
+        let mut groups: BTreeMap<String, (String, Vec<String>)> = BTreeMap::new();
+
+        for project in projects {
+            let Some(raw) = value(project).filter(|v| !ParsedFields::is_placeholder(v)) else {
+                continue;
+            };
+            groups
+                .entry(raw.trim().to_lowercase())
+                .or_insert_with(|| (raw.trim().to_string(), Vec::new()))
+                .1
+                .push(project.id.clone());
+        }
+
+        groups
+            .into_values()
+            .filter(|(_, ids)| ids.len() > 1)
+            .map(|(value, project_ids)| DuplicateGroup { value, project_ids })
+            .collect()
+    }
+
+    /// Countries written more than one way, e.g. "USA" / "U.S.A." / "United States"
+    fn country_spellings(projects: &[Project]) -> Vec<SpellingGroup> {
+        let mut groups: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+
+        for project in projects {
+            let Some(country) = project.country.as_deref().filter(|c| !ParsedFields::is_placeholder(c)) else {
+                continue;
+            };
+            // Spellings of a known country group under its ISO name, e.g. "US" with "United States"
+            let normalized = match project.parsed.country {
+                Some(iso) => iso.name.to_string(),
+                None => Self::normalize_spelling(country),
+            };
+            *groups
+                .entry(normalized)
+                .or_default()
+                .entry(country.to_string())
+                .or_insert(0) += 1;
+        }
+
+        groups
+            .into_iter()
+            .filter(|(_, variants)| variants.len() > 1)
+            .map(|(normalized, variants)| SpellingGroup {
+                normalized,
+                variants: variants
+                    .into_iter()
+                    .map(|(value, count)| SpellingVariant { value, count })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Lowercase letters and digits only, without a leading "the"
+    fn normalize_spelling(value: &str) -> String {
+        let lower = value.trim().to_lowercase();
+        let lower = lower.strip_prefix("the ").unwrap_or(&lower);
+        lower.chars().filter(|c| c.is_alphanumeric()).collect()
+    }
+
+    /// Non-positive awards and awards outside the Tukey fences of the whole dataset
+    fn funding_outliers(projects: &[Project]) -> Vec<FundingOutlier> {
+        let mut amounts: Vec<f64> = projects.iter().filter_map(|p| p.total_awarded).collect();
+        amounts.sort_by(|a, b| a.total_cmp(b));
+
+        let fences = (amounts.len() >= 4).then(|| {
+            let q1 = Self::percentile(&amounts, 0.25);
+            let q3 = Self::percentile(&amounts, 0.75);
+            let iqr = q3 - q1;
+            (q1 - OUTLIER_IQR_MULTIPLIER * iqr, q3 + OUTLIER_IQR_MULTIPLIER * iqr)
+        });
+
+        let mut outliers: Vec<FundingOutlier> = projects
+            .iter()
+            .filter_map(|p| {
+                let amount = p.total_awarded?;
+                let reason = if amount <= 0.0 {
+                    "award is zero or negative".to_string()
+                } else {
+                    let (low, high) = fences?;
+                    if amount > high {
+                        format!("above upper fence of {:.0}", high)
+                    } else if amount < low {
+                        format!("below lower fence of {:.0}", low)
+                    } else {
+                        return None;
+                    }
+                };
+
+                Some(FundingOutlier {
+                    project_id: p.id.clone(),
+                    title: p.title.clone(),
+                    total_awarded: amount,
+                    reason,
+                })
+            })
+            .collect();
+        outliers.sort_by(|a, b| b.total_awarded.total_cmp(&a.total_awarded));
+
+        outliers
+    }
+
+    /// Linearly interpolated percentile of sorted values
+    fn percentile(sorted: &[f64], p: f64) -> f64 {
+        let rank = p * (sorted.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+    }
+
+    /// Quarter entries that don't parse as "Q<n> '<yy>"
+    fn unparseable_quarters(projects: &[Project]) -> Vec<UnparseableValue> {
+        projects
+            .iter()
+            .flat_map(|p| {
+                ParsedFields::split_list(p.quarters.as_deref())
+                    .filter(|q| Quarter::parse(q).is_none())
+                    .map(|q| UnparseableValue {
+                        project_id: p.id.clone(),
+                        title: p.title.clone(),
+                        value: q.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Projects without a record in an enrichment source; empty sources are reported separately
+    fn missing_enrichment(dataset: &Dataset) -> (Vec<MissingEnrichment>, Vec<String>) {
+        let enrichment = &dataset.enrichment;
+        let sources: [(&str, HashSet<&String>); 4] = [
+            ("website", enrichment.website.keys().collect()),
+            ("social", enrichment.social.keys().collect()),
+            ("team", enrichment.team.keys().collect()),
+            ("regions", enrichment.regions.keys().collect()),
+        ];
+
+        let empty_sources = sources
+            .iter()
+            .filter(|(_, ids)| ids.is_empty())
+            .map(|(name, _)| name.to_string())
+            .collect();
+
+        let missing = dataset
+            .projects
+            .iter()
+            .filter_map(|p| {
+                let missing: Vec<String> = sources
+                    .iter()
+                    .filter(|(_, ids)| !ids.is_empty() && !ids.contains(&p.id))
+                    .map(|(name, _)| name.to_string())
+                    .collect();
+
+                (!missing.is_empty()).then(|| MissingEnrichment {
+                    project_id: p.id.clone(),
+                    title: p.title.clone(),
+                    missing,
+                })
+            })
+            .collect();
+
+        (missing, empty_sources)
+    }
+
+    /// Render the report as a Markdown document
+    pub fn to_markdown(report: &DataQualityReport) -> String {
+        let mut md = String::new();
+        Self::write_markdown(&mut md, report).expect("writing to a String cannot fail");
+        md
+    }
+
+    fn write_markdown(md: &mut String, report: &DataQualityReport) -> std::fmt::Result {
+        writeln!(md, "# Data Quality Report\n")?;
+        writeln!(
+            md,
+            "Snapshot `{}` with {} projects, generated {}. **{} issues found.**\n",
+            report.snapshot_id,
+            report.project_count,
+            report.generated_at.format("%Y-%m-%d %H:%M UTC"),
+            report.issue_count
+        )?;
+
+        writeln!(md, "## Missing fields\n")?;
+        if report.missing_fields.is_empty() {
+            writeln!(md, "No missing fields.\n")?;
+        } else {
+            writeln!(md, "| Field | Missing | % |\n|---|---:|---:|")?;
+            for field in &report.missing_fields {
+                writeln!(md, "| {} | {} | {:.1} |", field.field, field.missing, field.missing_percentage)?;
+            }
+            writeln!(md)?;
+        }
+
+        for (heading, groups) in [
+            ("Duplicate titles", &report.duplicate_titles),
+            ("Duplicate companies", &report.duplicate_companies),
+        ] {
+            writeln!(md, "## {}\n", heading)?;
+            if groups.is_empty() {
+                writeln!(md, "None.\n")?;
+                continue;
+            }
+            for group in groups {
+                writeln!(md, "- **{}**: {}", Self::escape(&group.value), group.project_ids.join(", "))?;
+            }
+            writeln!(md)?;
+        }
+
+        writeln!(md, "## Inconsistent country spellings\n")?;
+        if report.country_spellings.is_empty() {
+            writeln!(md, "None.\n")?;
+        } else {
+            for group in &report.country_spellings {
+                let variants: Vec<String> = group
+                    .variants
+                    .iter()
+                    .map(|v| format!("\"{}\" ({})", Self::escape(&v.value), v.count))
+                    .collect();
+                writeln!(md, "- {}", variants.join(", "))?;
+            }
+            writeln!(md)?;
+        }
+
+        writeln!(md, "## Funding outliers\n")?;
+        if report.funding_outliers.is_empty() {
+            writeln!(md, "None.\n")?;
+        } else {
+            writeln!(md, "| Project | Awarded | Reason |\n|---|---:|---|")?;
+            for outlier in &report.funding_outliers {
+                writeln!(
+                    md,
+                    "| {} (`{}`) | {:.0} | {} |",
+                    Self::escape(&outlier.title),
+                    outlier.project_id,
+                    outlier.total_awarded,
+                    outlier.reason
+                )?;
+            }
+            writeln!(md)?;
+        }
+
+        writeln!(md, "## Unparseable quarters\n")?;
+        if report.unparseable_quarters.is_empty() {
+            writeln!(md, "None.\n")?;
+        } else {
+            for value in &report.unparseable_quarters {
+                writeln!(md, "- `{}`: \"{}\"", value.project_id, Self::escape(&value.value))?;
+            }
+            writeln!(md)?;
+        }
+
+        writeln!(md, "## Missing enrichment\n")?;
+        if !report.empty_enrichment_sources.is_empty() {
+            writeln!(md, "Sources with no records: {}.\n", report.empty_enrichment_sources.join(", "))?;
+        }
+        if report.missing_enrichment.is_empty() {
+            writeln!(md, "Every project has a record in each loaded source.")?;
+        } else {
+            writeln!(md, "| Project | Missing |\n|---|---|")?;
+            for project in &report.missing_enrichment {
+                writeln!(
+                    md,
+                    "| {} (`{}`) | {} |",
+                    Self::escape(&project.title),
+                    project.project_id,
+                    project.missing.join(", ")
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keep data values from breaking table cells
+    fn escape(value: &str) -> String {
+        value.replace('|', "\\|").replace('\n', " ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::DatasetService;
+    use shared::DatasetLoadReport;
+
+    #[test]
+    fn test_audit_finds_data_problems() {
+        let mut projects: Vec<Project> = serde_json::from_value(serde_json::json!([
+            {"title": "Alpha", "company": "Acme", "country": "USA", "total_awarded": 50000.0, "quarters": "Q1 '23"},
+            {"title": "alpha ", "company": "ACME", "country": "U.S.A.", "total_awarded": 60000.0, "quarters": "Spring 2023"},
+            {"title": "Beta", "country": "United States", "total_awarded": 55000.0, "quarters": "Q2 '23"},
+            {"title": "Gamma", "country": "Kenya", "total_awarded": 52000.0},
+            {"title": "Delta", "country": "Kenya", "total_awarded": 900000.0}
+        ]))
+        .unwrap();
+        DatasetService::assign_ids(&mut projects);
+        let dataset = Dataset::new(projects, DatasetLoadReport::new("test.json"), "abc".to_string());
+
+        let report = DataQualityService::audit(&dataset);
+
+        assert_eq!(report.duplicate_titles.len(), 1);
+        assert_eq!(report.duplicate_companies[0].project_ids.len(), 2);
+        assert_eq!(report.country_spellings.len(), 1);
+        assert_eq!(report.country_spellings[0].normalized, "United States");
+        assert_eq!(report.country_spellings[0].variants.len(), 3);
+        assert_eq!(report.funding_outliers.len(), 1);
+        assert_eq!(report.funding_outliers[0].project_id, "delta");
+        assert_eq!(report.unparseable_quarters[0].value, "Spring 2023");
+        assert!(report.missing_fields.iter().any(|f| f.field == "company" && f.missing == 3));
+        assert_eq!(report.empty_enrichment_sources.len(), 4);
+
+        let markdown = DataQualityService::to_markdown(&report);
+        assert!(markdown.contains("## Funding outliers"));
+        assert!(markdown.contains("Spring 2023"));
+    }
+}
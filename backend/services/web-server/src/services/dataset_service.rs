@@ -0,0 +1,470 @@
+use shared::{slugify, DatasetLoadReport, Project, RefusedLoad, RejectedRecord};
+use lib_core::{DataConfig, ProjectSource};
+use lib_web::AppError;
+use serde_json::Value;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tracing::{error, info, warn};
+
+use crate::{
+    repositories::ProjectRepository,
+    services::{EnrichedService, SnapshotService},
+    AppState, Dataset,
+};
+
+pub struct DatasetService;
+
+/// A failed dataset load; a dataset refused by `DatasetService::validate` keeps its report
+#[derive(Debug)]
+pub struct LoadError {
+    pub error: AppError,
+    pub refused: Option<RefusedLoad>,
+}
+
+impl From<AppError> for LoadError {
+    fn from(error: AppError) -> Self {
+        Self { error, refused: None }
+    }
+}
+
+impl DatasetService {
+    /// Load and validate the configured project source as a complete dataset. `pool` is only
+    /// needed for the PostgreSQL source; nothing is written anywhere.
+    pub async fn load_dataset(config: &DataConfig, pool: Option<&PgPool>) -> Result<Dataset, LoadError> {
+        let mut dataset = match config.source {
+            ProjectSource::Json => {
+                let source_modified = Self::modified_time(&config.projects_file).await;
+                let contents = Self::read_source(&config.projects_file).await?;
+                let (projects, report) =
+                    Self::parse_projects(&contents, &config.projects_file.display().to_string())?;
+
+                let mut dataset = Dataset::new(projects, report, SnapshotService::checksum(contents.as_bytes()));
+                dataset.source_modified = source_modified;
+                dataset
+            }
+            ProjectSource::Postgres => {
+                let pool = pool.ok_or_else(|| {
+                    AppError::InternalError("PROJECT_SOURCE=postgres needs a database connection".to_string())
+                })?;
+                // Ids were assigned when the projects were imported
+                let projects = ProjectRepository::find_all(pool).await?;
+                let serialized = serde_json::to_vec(&projects)
+                    .map_err(|e| AppError::InternalError(format!("Failed to serialize projects: {}", e)))?;
+
+                let mut report = DatasetLoadReport::new("postgres:projects");
+                report.total_records = projects.len();
+                report.loaded_count = projects.len();
+
+                Dataset::new(projects, report, SnapshotService::checksum(&serialized))
+            }
+        };
+        if let Err(error) = Self::validate(&dataset.report, config) {
+            let refused = RefusedLoad { reason: error.to_string(), report: dataset.report };
+            return Err(LoadError { error, refused: Some(refused) });
+        }
+        Self::join_extras(config, &mut dataset).await?;
+
+        Ok(dataset)
+    }
+
+    /// Add the aliases file and the enrichment to a freshly parsed dataset
+    async fn join_extras(config: &DataConfig, dataset: &mut Dataset) -> Result<(), AppError> {
+        dataset.add_aliases(Self::load_aliases(&config.aliases_file).await?);
+        let enrichment = EnrichedService::load(&config.enriched_dir(), dataset).await;
+        dataset.set_enrichment(enrichment);
+        Ok(())
+    }
+
+    /// Record a dataset about to be served in the snapshot archive
+    pub async fn archive_snapshot(config: &DataConfig, dataset: &Dataset) {
+        // A failed archive write only costs us history, never the load itself
+        if let Err(e) = SnapshotService::archive(config, dataset).await {
+            warn!("Failed to archive snapshot {}: {}", dataset.snapshot.id, e);
+        }
+    }
+
+    /// Import the projects file into the `projects` table, replacing its contents, and archive
+    /// it as a snapshot
+    pub async fn import_to_postgres(config: &DataConfig, pool: &PgPool) -> Result<DatasetLoadReport, AppError> {
+        let contents = Self::read_source(&config.projects_file).await?;
+        let (projects, report) = Self::parse_projects(&contents, &config.projects_file.display().to_string())?;
+        Self::validate(&report, config)?;
+
+        // The checksum the configured source will load it under, so serving it reuses the snapshot
+        let checksum = match config.source {
+            ProjectSource::Json => SnapshotService::checksum(contents.as_bytes()),
+            ProjectSource::Postgres => SnapshotService::checksum(
+                &serde_json::to_vec(&projects)
+                    .map_err(|e| AppError::InternalError(format!("Failed to serialize projects: {}", e)))?,
+            ),
+        };
+        let mut dataset = Dataset::new(projects, report, checksum);
+        Self::join_extras(config, &mut dataset).await?;
+
+        let imported = ProjectRepository::replace_all(pool, &dataset.projects).await?;
+        info!(" Imported {} projects into PostgreSQL", imported);
+        Self::archive_snapshot(config, &dataset).await;
+
+        Ok(dataset.report)
+    }
+
+    /// Reject datasets that would silently empty or gut the API
+    pub fn validate(report: &DatasetLoadReport, config: &DataConfig) -> Result<(), AppError> {
+        if report.loaded_count == 0 {
+            return Err(AppError::BadRequest(format!(
+                "{} contains no valid projects ({} rejected)",
+                report.source_path, report.rejected_count
+            )));
+        }
+
+        let rejected_ratio = report.rejected_count as f64 / report.total_records as f64;
+        if rejected_ratio > config.max_rejected_ratio {
+            return Err(AppError::BadRequest(format!(
+                "{} rejected {} of {} records, above the allowed ratio of {}",
+                report.source_path, report.rejected_count, report.total_records, config.max_rejected_ratio
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Reload the projects file and swap it in; the current dataset stays on any failure.
+    /// A source whose checksum matches the current snapshot is left alone and gives `None`.
+    pub async fn reload(state: &AppState) -> Result<Option<DatasetLoadReport>, AppError> {
+        let _guard = state.reload_lock.lock().await;
+        let current = state.dataset();
+
+        // Checking the file first spares parsing, indexing and archiving an unchanged dataset
+        if state.data_config.source == ProjectSource::Json {
+            let contents = Self::read_source(&state.data_config.projects_file).await?;
+            if SnapshotService::checksum(contents.as_bytes()) == current.snapshot.checksum {
+                info!(" Dataset unchanged, keeping snapshot {}", current.snapshot.id);
+                return Ok(None);
+            }
+        }
+
+        let mut dataset = match Self::load_dataset(&state.data_config, Some(&state.db)).await {
+            Ok(dataset) => dataset,
+            Err(LoadError { error, refused }) => {
+                if let Some(refused) = refused {
+                    Self::log_report(&refused.report);
+                    state.refuse_load(refused);
+                }
+                return Err(error);
+            }
+        };
+        if dataset.snapshot.checksum == current.snapshot.checksum {
+            info!(" Dataset unchanged, keeping snapshot {}", current.snapshot.id);
+            return Ok(None);
+        }
+        Self::archive_snapshot(&state.data_config, &dataset).await;
+        dataset.inherit_aliases(&current);
+        Self::log_report(&dataset.report);
+
+        let report = dataset.report.clone();
+        info!(" Activated dataset snapshot {}", dataset.snapshot.id);
+        state.replace_dataset(dataset);
+
+        Ok(Some(report))
+    }
+
+    /// Poll the projects file and reload whenever its modification time changes
+    pub fn spawn_watcher(state: AppState) {
+        let interval_secs = state.data_config.watch_interval_secs;
+        if interval_secs == 0 || state.data_config.source != ProjectSource::Json {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let path = state.data_config.projects_file.clone();
+            let mut last_seen = state.dataset().source_modified;
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+
+            info!("Watching {} for changes every {}s", path.display(), interval_secs);
+
+            loop {
+                interval.tick().await;
+
+                let modified = Self::modified_time(&path).await;
+                if modified.is_none() || modified == last_seen {
+                    continue;
+                }
+
+                // Remember the attempt so a broken file is not reloaded on every tick
+                last_seen = modified;
+
+                info!("Detected change in {}, reloading dataset", path.display());
+                if let Err(e) = Self::reload(&state).await {
+                    error!("Dataset reload failed, keeping current dataset: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Give every project a unique id, keeping (slugified) ids the source provides.
+    /// Projects sharing a slug are ordered by their content, not their position in the source,
+    /// so reordering rows keeps every id: the first keeps the slug, the others add their
+    /// category, then a counter.
+    pub fn assign_ids(projects: &mut [Project]) {
+        let bases: Vec<String> = projects
+            .iter()
+            .map(|project| match slugify(&project.id) {
+                id if !id.is_empty() => id,
+                _ => match slugify(&project.title) {
+                    slug if !slug.is_empty() => slug,
+                    _ => "project".to_string(),
+                },
+            })
+            .collect();
+
+        let mut groups: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+        for (index, base) in bases.iter().enumerate() {
+            groups.entry(base.as_str()).or_default().push(index);
+        }
+
+        // Every slug stays with one project of its own group, so a suffix never takes it
+        let mut taken: HashSet<String> = groups.keys().map(|base| base.to_string()).collect();
+        let mut ids = vec![String::new(); projects.len()];
+        for (base, mut members) in groups {
+            // A stable sort: only identical records fall back to load order
+            members.sort_by_key(|&index| Self::content_key(&projects[index]));
+            ids[members[0]] = base.to_string();
+
+            for &index in &members[1..] {
+                let mut id = base.to_string();
+                if let Some(category) = projects[index].category.as_deref().map(slugify).filter(|c| !c.is_empty()) {
+                    id = format!("{}-{}", base, category);
+                }
+                let mut counter = 2;
+                while taken.contains(&id) {
+                    id = format!("{}-{}", base, counter);
+                    counter += 1;
+                }
+                taken.insert(id.clone());
+                ids[index] = id;
+            }
+        }
+
+        for (project, id) in projects.iter_mut().zip(ids) {
+            project.id = id;
+        }
+    }
+
+    /// What orders projects sharing a slug
+    fn content_key(project: &Project) -> (Option<&str>, &str, Option<&str>, Option<&str>, Option<&str>) {
+        (
+            project.category.as_deref(),
+            project.title.as_str(),
+            project.company.as_deref(),
+            project.country.as_deref(),
+            project.description.as_deref(),
+        )
+    }
+
+    /// Read the optional aliases file; a missing file means no extra aliases
+    async fn load_aliases(path: &Path) -> Result<HashMap<String, String>, AppError> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse {}: {}", path.display(), e))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(e) => Err(AppError::InternalError(format!("Failed to read {}: {}", path.display(), e))),
+        }
+    }
+
+    async fn read_source(path: &Path) -> Result<String, AppError> {
+        tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to read {}: {}", path.display(), e)))
+    }
+
+    async fn modified_time(path: &Path) -> Option<SystemTime> {
+        tokio::fs::metadata(path)
+            .await
+            .and_then(|m| m.modified())
+            .ok()
+    }
+
+    /// Parse the `by_category` structure, keeping a record of everything rejected
+    pub fn parse_projects(
+        contents: &str,
+        source_path: &str,
+    ) -> Result<(Vec<Project>, DatasetLoadReport), AppError> {
+        let data: Value = serde_json::from_str(contents)
+            .map_err(|e| AppError::InternalError(format!("Failed to parse {}: {}", source_path, e)))?;
+
+        let by_category = data
+            .get("by_category")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| {
+                AppError::InternalError(format!("{} has no `by_category` object", source_path))
+            })?;
+
+        let mut report = DatasetLoadReport::new(source_path);
+        let mut projects = Vec::new();
+
+        for (category, bucket) in by_category {
+            let Some(records) = bucket.as_array() else {
+                report.record_rejected(RejectedRecord {
+                    category: category.clone(),
+                    index: 0,
+                    title: None,
+                    reason: "category bucket is not an array".to_string(),
+                });
+                continue;
+            };
+
+            for (index, record) in records.iter().enumerate() {
+                match serde_json::from_value::<Project>(record.clone()) {
+                    Ok(project) => {
+                        projects.push(project);
+                        report.record_loaded();
+                    }
+                    Err(e) => report.record_rejected(RejectedRecord {
+                        category: category.clone(),
+                        index,
+                        title: record.get("title").and_then(|t| t.as_str()).map(String::from),
+                        reason: e.to_string(),
+                    }),
+                }
+            }
+        }
+        Self::assign_ids(&mut projects);
+
+        Ok((projects, report))
+    }
+
+    /// Write the load summary and every rejected record to the log
+    pub fn log_report(report: &DatasetLoadReport) {
+        info!(
+            " Loaded {} of {} projects from {}",
+            report.loaded_count, report.total_records, report.source_path
+        );
+
+        for (field, values) in &report.unknown_values {
+            warn!("Unrecognized {} values: {:?}", field, values);
+        }
+
+        if report.rejected_count == 0 {
+            return;
+        }
+
+        warn!(
+            "Rejected {} project records: {:?}",
+            report.rejected_count, report.rejected_by_category
+        );
+        for rejected in &report.rejected {
+            warn!(
+                "  [{}#{}] {}: {}",
+                rejected.category,
+                rejected.index,
+                rejected.title.as_deref().unwrap_or("<untitled>"),
+                rejected.reason
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reports_rejected_records() {
+        let contents = r#"{
+            "by_category": {
+                "Infrastructure": [
+                    {"title": "Good Project", "total_awarded": 50000},
+                    {"title": "Bad Funding", "total_awarded": "lots"}
+                ],
+                "Applications": [
+                    {"description": "no title"}
+                ]
+            }
+        }"#;
+
+        let (projects, report) = DatasetService::parse_projects(contents, "test.json").unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(report.total_records, 3);
+        assert_eq!(report.rejected_count, 2);
+        assert_eq!(report.rejected_by_category.get("Infrastructure"), Some(&1));
+        assert_eq!(report.rejected_by_category.get("Applications"), Some(&1));
+        assert!(report
+            .rejected
+            .iter()
+            .any(|r| r.title.as_deref() == Some("Bad Funding") && r.index == 1));
+    }
+
+    #[test]
+    fn test_validate_rejects_mostly_broken_dataset() {
+        let contents = r#"{
+            "by_category": {
+                "Infrastructure": [
+                    {"title": "Good Project"},
+                    {"title": "Bad One", "soroban": "maybe"},
+                    {"title": "Bad Two", "soroban": "maybe"}
+                ]
+            }
+        }"#;
+        let config = DataConfig {
+            data_dir: "data".into(),
+            projects_file: "test.json".into(),
+            aliases_file: "aliases.json".into(),
+            source: ProjectSource::Json,
+            watch_interval_secs: 0,
+            max_rejected_ratio: 0.5,
+            snapshot_retention: 0,
+        };
+
+        let (_, report) = DatasetService::parse_projects(contents, "test.json").unwrap();
+        assert!(DatasetService::validate(&report, &config).is_err());
+
+        let (_, empty) = DatasetService::parse_projects(r#"{"by_category": {}}"#, "test.json").unwrap();
+        assert!(DatasetService::validate(&empty, &config).is_err());
+    }
+
+    #[test]
+    fn test_assign_ids_is_deterministic_and_unique() {
+        let contents = r#"{
+            "by_category": {
+                "Applications": [
+                    {"title": "Stellar Pay!"},
+                    {"title": "Stellar Pay"},
+                    {"title": "Stellar Pay", "company": "Pay Inc"},
+                    {"id": "custom-id", "title": "Renamed Project"}
+                ],
+                "Infrastructure": [
+                    {"title": "Stellar Pay", "category": "Infrastructure"}
+                ]
+            }
+        }"#;
+
+        let (projects, _) = DatasetService::parse_projects(contents, "test.json").unwrap();
+        let ids: Vec<&str> = projects.iter().map(|p| p.id.as_str()).collect();
+
+        assert_eq!(
+            ids,
+            vec!["stellar-pay-3", "stellar-pay", "stellar-pay-2", "custom-id", "stellar-pay-infrastructure"]
+        );
+
+        let (again, _) = DatasetService::parse_projects(contents, "test.json").unwrap();
+        assert_eq!(projects, again);
+
+        // Reordered rows keep their ids
+        let mut reordered = projects.clone();
+        reordered.reverse();
+        for project in reordered.iter_mut().filter(|p| p.id != "custom-id") {
+            project.id.clear();
+        }
+        DatasetService::assign_ids(&mut reordered);
+        reordered.reverse();
+        assert_eq!(projects, reordered);
+    }
+
+    #[test]
+    fn test_parse_requires_by_category() {
+        assert!(DatasetService::parse_projects(r#"{"projects": []}"#, "test.json").is_err());
+    }
+}
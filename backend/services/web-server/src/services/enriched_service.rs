@@ -1,63 +1,177 @@
-use shared::{EnrichedProjectData, WebsiteMetadata, SocialLinks, DiscordInfo, TwitterInfo};
+use shared::{
+    EnrichedProjectData, ProjectEnrichment, RegionInfo, SocialLinks, TeamProfile, WebsiteMetadata,
+};
 use lib_web::AppError;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::Path;
+use tracing::{info, warn};
+
+use crate::{services::{ProjectView, SnapshotService}, Dataset};
+
+/// Enrichment files joined to the dataset, keyed by project id
+#[derive(Debug, Default)]
+pub struct Enrichment {
+    pub website: HashMap<String, WebsiteMetadata>,
+    pub social: HashMap<String, SocialLinks>,
+    pub team: HashMap<String, TeamProfile>,
+    pub regions: HashMap<String, RegionInfo>,
+    /// SHA-256 over the files it was loaded from, so snapshots can record which they saw
+    pub checksum: String,
+}
+
+/// Which enrichment parts a request asked for with `include=`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EnrichmentInclude {
+    pub social: bool,
+    pub website: bool,
+    pub team: bool,
+    pub regions: bool,
+}
+
+impl EnrichmentInclude {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
 
 pub struct EnrichedService;
 
 impl EnrichedService {
-    pub async fn load_website_metadata() -> Result<HashMap<String, WebsiteMetadata>, AppError> {
-        let json_str = std::fs::read_to_string("data/enriched/website_metadata.json")
-            .map_err(|e| AppError::InternalError(format!("Failed to read website metadata: {}", e)))?;
+    /// Load every enrichment file in `dir` once, joining records to projects by title.
+    /// Missing or unreadable files leave that part empty rather than failing the load.
+    pub async fn load(dir: &Path, dataset: &Dataset) -> Enrichment {
+        let files = ["website_metadata.json", "social_links.json", "team_profiles.json", "projects_with_regions.json"]
+            .map(|file| dir.join(file));
+        let mut contents: Vec<Option<String>> = Vec::with_capacity(files.len());
+        for path in &files {
+            contents.push(Self::read(path).await);
+        }
 
-        let data: Value = serde_json::from_str(&json_str)
-            .map_err(|e| AppError::InternalError(format!("Failed to parse website metadata: {}", e)))?;
+        // One digest per file, so moving records between files changes the checksum
+        let digests: String = contents
+            .iter()
+            .map(|c| SnapshotService::checksum(c.as_deref().unwrap_or_default().as_bytes()))
+            .collect();
 
-        let mut metadata_map = HashMap::new();
+        let enrichment = Enrichment {
+            website: Self::join_by_title(contents[0].as_deref(), &files[0], "scraped_websites", dataset),
+            social: Self::join_by_title(contents[1].as_deref(), &files[1], "projects", dataset),
+            team: Self::join_by_title(contents[2].as_deref(), &files[2], "projects", dataset),
+            regions: Self::join_by_title(contents[3].as_deref(), &files[3], "projects", dataset),
+            checksum: SnapshotService::checksum(digests.as_bytes()),
+        };
 
-        if let Some(websites) = data.get("scraped_websites").and_then(|v| v.as_array()) {
-            for site in websites {
-                if let Some(title) = site.get("title").and_then(|v| v.as_str()) {
-                    if let Ok(metadata) = serde_json::from_value::<WebsiteMetadata>(site.clone()) {
-                        metadata_map.insert(title.to_string(), metadata);
-                    }
-                }
+        info!(
+            " Joined enrichment: {} website, {} social, {} team, {} region records",
+            enrichment.website.len(),
+            enrichment.social.len(),
+            enrichment.team.len(),
+            enrichment.regions.len()
+        );
+
+        enrichment
+    }
+
+    async fn read(path: &Path) -> Option<String> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(contents) => Some(contents),
+            Err(e) => {
+                warn!("Skipping enrichment {}: {}", path.display(), e);
+                None
             }
         }
-
-        Ok(metadata_map)
     }
 
-    pub async fn load_social_links() -> Result<HashMap<String, SocialLinks>, AppError> {
-        let json_str = std::fs::read_to_string("data/enriched/social_links.json")
-            .map_err(|e| AppError::InternalError(format!("Failed to read social links: {}", e)))?;
+    fn join_by_title<T: DeserializeOwned>(
+        contents: Option<&str>,
+        path: &Path,
+        array_key: &str,
+        dataset: &Dataset,
+    ) -> HashMap<String, T> {
+        let mut by_id = HashMap::new();
 
-        let data: Value = serde_json::from_str(&json_str)
-            .map_err(|e| AppError::InternalError(format!("Failed to parse social links: {}", e)))?;
+        let Some(contents) = contents else {
+            return by_id;
+        };
+        let data = match serde_json::from_str::<Value>(contents) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!("Failed to parse {}: {}", path.display(), e);
+                return by_id;
+            }
+        };
 
-        let mut social_map = HashMap::new();
+        let records = data.get(array_key).and_then(|v| v.as_array()).into_iter().flatten();
+        for record in records {
+            let Some(id) = record
+                .get("title")
+                .and_then(|v| v.as_str())
+                .and_then(|title| dataset.canonical_id(title))
+            else {
+                continue;
+            };
 
-        if let Some(projects) = data.get("projects").and_then(|v| v.as_array()) {
-            for project in projects {
-                if let Some(title) = project.get("title").and_then(|v| v.as_str()) {
-                    if let Ok(social) = serde_json::from_value::<SocialLinks>(project.clone()) {
-                        social_map.insert(title.to_string(), social);
-                    }
+            if let Ok(parsed) = serde_json::from_value::<T>(record.clone()) {
+                by_id.entry(id.to_string()).or_insert(parsed);
+            }
+        }
+
+        by_id
+    }
+
+    /// Parse `include=social,website,team,regions`; unknown parts are rejected
+    pub fn parse_include(include: Option<&str>) -> Result<EnrichmentInclude, AppError> {
+        let mut parsed = EnrichmentInclude::default();
+
+        for part in include.unwrap_or_default().split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            match part {
+                "social" => parsed.social = true,
+                "website" => parsed.website = true,
+                "team" => parsed.team = true,
+                "regions" => parsed.regions = true,
+                other => {
+                    return Err(AppError::BadRequest(format!(
+                        "Unknown include '{}', expected social, website, team or regions",
+                        other
+                    )))
                 }
             }
         }
 
-        Ok(social_map)
+        Ok(parsed)
+    }
+
+    /// The requested enrichment parts for one project
+    pub fn project_enrichment(
+        enrichment: &Enrichment,
+        project_id: &str,
+        include: EnrichmentInclude,
+    ) -> ProjectEnrichment {
+        ProjectEnrichment {
+            social: include.social.then(|| enrichment.social.get(project_id).cloned()).flatten(),
+            website: include.website.then(|| enrichment.website.get(project_id).cloned()).flatten(),
+            team: include.team.then(|| enrichment.team.get(project_id).cloned()).flatten(),
+            regions: include.regions.then(|| enrichment.regions.get(project_id).cloned()).flatten(),
+        }
+    }
+
+    /// Inline the requested enrichment into each project; a no-op without `include=`
+    pub fn attach(projects: &mut [ProjectView], enrichment: &Enrichment, include: EnrichmentInclude) {
+        if include.is_empty() {
+            return;
+        }
+
+        for view in projects {
+            view.enrichment = Some(Self::project_enrichment(enrichment, &view.project.id, include));
+        }
     }
 
-    pub async fn get_enriched_data(
-        project_title: &str,
-        website_metadata: &HashMap<String, WebsiteMetadata>,
-        social_links: &HashMap<String, SocialLinks>,
-    ) -> EnrichedProjectData {
+    pub fn get_enriched_data(project_id: &str, enrichment: &Enrichment) -> EnrichedProjectData {
         EnrichedProjectData {
-            website_metadata: website_metadata.get(project_title).cloned(),
-            social_links: social_links.get(project_title).cloned(),
+            website_metadata: enrichment.website.get(project_id).cloned(),
+            social_links: enrichment.social.get(project_id).cloned(),
             discord_info: None, // Can be extracted from social data if available
             twitter_info: None, // Can be extracted from social data if available
         }
@@ -0,0 +1,249 @@
+use shared::{
+    CountryRanking, CountryRankings, CountryStats, GeographicAnalysis, GeographicGaps, Project,
+    RegionStats, RegionalAnalysis, RegionalDensity, Subregion,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const TOP_COUNTRIES: usize = 10;
+
+/// Project count, funding and adoption rates for a group of projects
+struct GroupStats {
+    project_count: usize,
+    country_count: usize,
+    total_funding: f64,
+    avg_funding: f64,
+    mainnet_rate: f64,
+    soroban_rate: f64,
+}
+
+impl GroupStats {
+    fn of(projects: &[&Project]) -> Self {
+        let project_count = projects.len();
+        let total_funding: f64 = projects.iter().filter_map(|p| p.get_funding_amount_numeric()).sum();
+        let rate = |count: usize| {
+            if project_count > 0 {
+                count as f64 / project_count as f64
+            } else {
+                0.0
+            }
+        };
+
+        Self {
+            project_count,
+            country_count: projects
+                .iter()
+                .filter_map(|p| p.parsed.country.map(|c| c.alpha2))
+                .collect::<BTreeSet<_>>()
+                .len(),
+            total_funding,
+            avg_funding: if project_count > 0 { total_funding / project_count as f64 } else { 0.0 },
+            mainnet_rate: rate(projects.iter().filter(|p| p.is_mainnet()).count()),
+            soroban_rate: rate(projects.iter().filter(|p| p.uses_soroban()).count()),
+        }
+    }
+}
+
+pub struct GeographicService;
+
+impl GeographicService {
+    /// Countries ranked by total funding; unrecognized countries are counted, not ranked
+    pub fn country_rankings(projects: &[Project]) -> CountryRankings {
+        let mut by_country: HashMap<&str, Vec<&Project>> = HashMap::new();
+        for project in projects {
+            if let Some(country) = project.parsed.country {
+                by_country.entry(country.alpha2).or_default().push(project);
+            }
+        }
+
+        let mut countries: Vec<CountryRanking> = by_country
+            .values()
+            .map(|group| {
+                let country = group[0].parsed.country.expect("grouped by country");
+                let stats = GroupStats::of(group);
+                CountryRanking {
+                    country: country.name.to_string(),
+                    code: country.alpha2.to_string(),
+                    continent: country.continent().as_str().to_string(),
+                    subregion: country.subregion.as_str().to_string(),
+                    project_count: stats.project_count,
+                    total_funding: stats.total_funding,
+                    avg_funding: stats.avg_funding,
+                    mainnet_rate: stats.mainnet_rate,
+                    soroban_rate: stats.soroban_rate,
+                }
+            })
+            .collect();
+        countries.sort_by(|a, b| {
+            b.total_funding
+                .partial_cmp(&a.total_funding)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.country.cmp(&b.country))
+        });
+
+        CountryRankings {
+            total_countries: countries.len(),
+            unresolved_projects: projects.iter().filter(|p| p.parsed.country.is_none()).count(),
+            countries,
+        }
+    }
+
+    /// Funding and adoption per continent and per subregion
+    pub fn regional_analysis(projects: &[Project]) -> RegionalAnalysis {
+        let by_subregion = Self::by_subregion(projects);
+
+        let mut by_continent: BTreeMap<_, Vec<&Project>> = BTreeMap::new();
+        for (subregion, group) in &by_subregion {
+            by_continent.entry(subregion.continent()).or_default().extend(group);
+        }
+
+        let region_stats = |region: &str, code: &str, continent: &str, group: &[&Project]| {
+            let stats = GroupStats::of(group);
+            RegionStats {
+                region: region.to_string(),
+                code: code.to_string(),
+                continent: continent.to_string(),
+                project_count: stats.project_count,
+                country_count: stats.country_count,
+                total_funding: stats.total_funding,
+                avg_funding: stats.avg_funding,
+                mainnet_rate: stats.mainnet_rate,
+                soroban_rate: stats.soroban_rate,
+            }
+        };
+
+        let mut continents: Vec<RegionStats> = by_continent
+            .iter()
+            .map(|(continent, group)| region_stats(continent.as_str(), continent.code(), continent.as_str(), group))
+            .collect();
+        let mut regions: Vec<RegionStats> = by_subregion
+            .iter()
+            .map(|(subregion, group)| {
+                region_stats(subregion.as_str(), subregion.m49(), subregion.continent().as_str(), group)
+            })
+            .collect();
+
+        for stats in [&mut continents, &mut regions] {
+            stats.sort_by(|a, b| {
+                b.total_funding
+                    .partial_cmp(&a.total_funding)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+
+        RegionalAnalysis { continents, regions }
+    }
+
+    /// Subregions with and without funded projects
+    pub fn geographic_gaps(projects: &[Project]) -> GeographicGaps {
+        let by_subregion = Self::by_subregion(projects);
+        let funded_regions: Vec<String> = by_subregion.keys().map(|s| s.as_str().to_string()).collect();
+        let underserved_regions = Self::underserved_regions(&by_subregion);
+
+        let expansion_recommendations = if underserved_regions.is_empty() {
+            "Every inhabited subregion has at least one funded project.".to_string()
+        } else {
+            format!(
+                "{} of {} subregions have no funded projects yet, including {}. Teams building for these markets face little direct competition for funding.",
+                underserved_regions.len(),
+                funded_regions.len() + underserved_regions.len(),
+                underserved_regions.iter().take(3).cloned().collect::<Vec<_>>().join(", ")
+            )
+        };
+
+        GeographicGaps {
+            funded_regions,
+            underserved_regions,
+            expansion_recommendations,
+        }
+    }
+
+    /// The handbook's geographic section, keyed by subregion
+    pub fn analysis(projects: &[Project]) -> GeographicAnalysis {
+        let by_subregion = Self::by_subregion(projects);
+
+        let regional_funding_density = by_subregion
+            .iter()
+            .map(|(subregion, group)| {
+                let stats = GroupStats::of(group);
+                let countries: BTreeSet<String> = group
+                    .iter()
+                    .filter_map(|p| p.parsed.country.map(|c| c.name.to_string()))
+                    .collect();
+                let density = RegionalDensity {
+                    project_count: stats.project_count,
+                    country_count: stats.country_count,
+                    total_funding: stats.total_funding,
+                    avg_funding: stats.avg_funding,
+                    countries: countries.into_iter().collect(),
+                };
+                (subregion.as_str().to_string(), density)
+            })
+            .collect();
+
+        let regional_specialization = by_subregion
+            .iter()
+            .map(|(subregion, group)| (subregion.as_str().to_string(), Self::type_counts(group)))
+            .collect();
+
+        let top_countries = Self::country_rankings(projects)
+            .countries
+            .into_iter()
+            .take(TOP_COUNTRIES)
+            .map(|ranking| {
+                let in_country: Vec<&Project> = projects
+                    .iter()
+                    .filter(|p| p.parsed.country.is_some_and(|c| c.alpha2 == ranking.code))
+                    .collect();
+                let top_type = Self::type_counts(&in_country)
+                    .into_iter()
+                    .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                    .map(|(project_type, _)| project_type)
+                    .unwrap_or_else(|| "Unknown".to_string());
+
+                CountryStats {
+                    country: ranking.country,
+                    project_count: ranking.project_count,
+                    total_funding: ranking.total_funding,
+                    avg_funding: ranking.avg_funding,
+                    top_type,
+                }
+            })
+            .collect();
+
+        GeographicAnalysis {
+            regional_funding_density,
+            top_countries,
+            underserved_regions: Self::underserved_regions(&by_subregion),
+            regional_specialization,
+        }
+    }
+
+    fn by_subregion(projects: &[Project]) -> BTreeMap<Subregion, Vec<&Project>> {
+        let mut by_subregion: BTreeMap<Subregion, Vec<&Project>> = BTreeMap::new();
+        for project in projects {
+            if let Some(country) = project.parsed.country {
+                by_subregion.entry(country.subregion).or_default().push(project);
+            }
+        }
+        by_subregion
+    }
+
+    /// Inhabited subregions without any project
+    fn underserved_regions(by_subregion: &BTreeMap<Subregion, Vec<&Project>>) -> Vec<String> {
+        Subregion::ALL
+            .into_iter()
+            .filter(|s| *s != Subregion::Antarctica && !by_subregion.contains_key(s))
+            .map(|s| s.as_str().to_string())
+            .collect()
+    }
+
+    fn type_counts(projects: &[&Project]) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for project in projects {
+            if let Some(project_type) = project.type_name() {
+                *counts.entry(project_type.to_string()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+}
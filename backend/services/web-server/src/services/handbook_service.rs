@@ -3,6 +3,8 @@ use lib_web::AppError;
 use std::fs;
 use serde_json::Value;
 
+use crate::services::GeographicService;
+
 pub struct HandbookService;
 
 impl HandbookService {
@@ -40,18 +42,6 @@ impl HandbookService {
         Ok(data)
     }
 
-    // ===== Geographic Analysis =====
-
-    pub async fn get_geographic_analysis() -> Result<GeographicAnalysis, AppError> {
-        let json_str = fs::read_to_string("data/analytics/geographic/market_analysis.json")
-            .map_err(|e| AppError::InternalError(format!("Failed to read geographic analysis: {}", e)))?;
-
-        let data: GeographicAnalysis = serde_json::from_str(&json_str)
-            .map_err(|e| AppError::InternalError(format!("Failed to parse geographic analysis: {}", e)))?;
-
-        Ok(data)
-    }
-
     // ===== Funding Efficiency =====
 
     pub async fn get_funding_efficiency() -> Result<FundingEfficiency, AppError> {
@@ -102,9 +92,9 @@ impl HandbookService {
 
     // ===== All Analytics Combined =====
 
-    pub async fn get_all_analytics() -> Result<Value, AppError> {
+    pub async fn get_all_analytics(projects: &[Project]) -> Result<Value, AppError> {
         let benchmarking = Self::get_benchmarking().await?;
-        let geographic = Self::get_geographic_analysis().await?;
+        let geographic = GeographicService::analysis(projects);
         let efficiency = Self::get_funding_efficiency().await?;
         let network = Self::get_network_analysis().await?;
         let portfolio = Self::get_portfolio_analysis().await?;
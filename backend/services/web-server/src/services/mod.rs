@@ -4,6 +4,22 @@ mod analytics_service;
 mod predictor_service;
 mod enriched_service;
 mod handbook_service;
+mod dataset_service;
+mod snapshot_service;
+mod data_quality_service;
+mod geographic_service;
+mod search_service;
+mod suggest_service;
+mod query_service;
+mod pagination_service;
+mod sort_service;
+mod similarity_service;
+mod saved_search_service;
+mod projection_service;
+mod timeline_service;
+mod trait_service;
+#[cfg(test)]
+pub(crate) mod test_support;
 
 pub use auth_service::*;
 pub use project_service::*;
@@ -11,3 +27,17 @@ pub use analytics_service::*;
 pub use predictor_service::*;
 pub use enriched_service::*;
 pub use handbook_service::*;
+pub use dataset_service::*;
+pub use snapshot_service::*;
+pub use data_quality_service::*;
+pub use geographic_service::*;
+pub use search_service::*;
+pub use suggest_service::*;
+pub use query_service::*;
+pub use pagination_service::*;
+pub use sort_service::*;
+pub use similarity_service::*;
+pub use saved_search_service::*;
+pub use projection_service::*;
+pub use timeline_service::*;
+pub use trait_service::*;
@@ -0,0 +1,206 @@
+use lib_web::AppError;
+use serde::{Deserialize, Serialize};
+use shared::Project;
+use std::cmp::Ordering;
+
+use crate::services::{SortSpec, SortValue};
+
+pub const DEFAULT_PER_PAGE: usize = 20;
+/// Largest `per_page` a listing accepts
+pub const MAX_PER_PAGE: usize = 100;
+
+/// The project a page starts after (or ends before), by value rather than offset, so a
+/// cursor keeps its place when projects are added, removed or reordered between requests
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    /// Canonical sort the cursor was issued for
+    sort: String,
+    key: Vec<SortValue>,
+    id: String,
+    /// Page of projects before the boundary rather than after it
+    #[serde(default)]
+    before: bool,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        lib_utils::encode_url_safe(&serde_json::to_vec(self).expect("cursor serializes"))
+    }
+
+    fn decode(value: &str) -> Result<Self, AppError> {
+        lib_utils::decode_url_safe(value.trim())
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| AppError::BadRequest("Invalid cursor".to_string()))
+    }
+
+    fn order(&self, spec: &SortSpec, key: &[SortValue], id: &str) -> Ordering {
+        spec.compare(key, &self.key).then_with(|| id.cmp(&self.id))
+    }
+}
+
+/// One page of a sorted listing
+#[derive(Debug)]
+pub struct Page<'a> {
+    pub projects: Vec<&'a Project>,
+    /// 1-based; with a cursor, the page the first project would fall on
+    pub page: usize,
+    pub per_page: usize,
+    pub total_pages: usize,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+pub struct PaginationService;
+
+impl PaginationService {
+    /// Reject `page=0` and a `per_page` outside 1..=MAX_PER_PAGE
+    pub fn validate(page: Option<usize>, per_page: Option<usize>) -> Result<(usize, usize), AppError> {
+        let page = page.unwrap_or(1);
+        let per_page = per_page.unwrap_or(DEFAULT_PER_PAGE);
+        if page == 0 || per_page == 0 || per_page > MAX_PER_PAGE {
+            return Err(AppError::BadRequest(format!(
+                "page must be >= 1 and per_page between 1 and {}",
+                MAX_PER_PAGE
+            )));
+        }
+        Ok((page, per_page))
+    }
+
+    /// Sort by key, then id
+    pub fn sort(projects: &mut [(Vec<SortValue>, &Project)], spec: &SortSpec) {
+        projects.sort_by(|(a_key, a), (b_key, b)| spec.compare(a_key, b_key).then_with(|| a.id.cmp(&b.id)));
+    }
+
+    /// Cursor for the page after `project`, or before it with `before`, in `spec` order
+    pub fn cursor(spec: &SortSpec, key: &[SortValue], project: &Project, before: bool) -> String {
+        Cursor { sort: spec.to_string(), key: key.to_vec(), id: project.id.clone(), before }.encode()
+    }
+
+    /// Slice a listing ordered by `sort` with `spec`. A cursor takes precedence over `page`;
+    /// a page past the end is empty.
+    pub fn paginate<'a>(
+        sorted: &[(Vec<SortValue>, &'a Project)],
+        spec: &SortSpec,
+        page: Option<usize>,
+        per_page: Option<usize>,
+        cursor: Option<&str>,
+    ) -> Result<Page<'a>, AppError> {
+        let (page, per_page) = Self::validate(page, per_page)?;
+        let total = sorted.len();
+
+        let (start, end) = match cursor.filter(|c| !c.trim().is_empty()) {
+            Some(cursor) => {
+                let cursor = Cursor::decode(cursor)?;
+                if cursor.sort != spec.to_string() {
+                    return Err(AppError::BadRequest(
+                        "cursor belongs to a different sort".to_string(),
+                    ));
+                }
+                if cursor.before {
+                    let end = sorted.partition_point(|(key, p)| cursor.order(spec, key, &p.id) == Ordering::Less);
+                    (end.saturating_sub(per_page), end)
+                } else {
+                    let start = sorted.partition_point(|(key, p)| cursor.order(spec, key, &p.id) != Ordering::Greater);
+                    (start, (start + per_page).min(total))
+                }
+            }
+            None => {
+                let start = page.saturating_sub(1).saturating_mul(per_page).min(total);
+                (start, (start + per_page).min(total))
+            }
+        };
+
+        let boundary = |(key, project): &(Vec<SortValue>, &Project), before| Self::cursor(spec, key, project, before);
+
+        Ok(Page {
+            projects: sorted[start..end].iter().map(|(_, p)| *p).collect(),
+            page: if cursor.is_some() { start / per_page + 1 } else { page },
+            per_page,
+            total_pages: total.div_ceil(per_page),
+            next_cursor: (end > start && end < total).then(|| boundary(&sorted[end - 1], false)),
+            prev_cursor: (end > start && start > 0).then(|| boundary(&sorted[start], true)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{test_support, Enrichment};
+    use serde_json::json;
+    use shared::ProjectFilter;
+
+    fn project(id: &str, funding: f64) -> Project {
+        test_support::project(json!({ "title": id, "total_awarded": funding }))
+    }
+
+    fn ids(page: &Page) -> Vec<String> {
+        page.projects.iter().map(|p| p.id.clone()).collect()
+    }
+
+    fn sorted<'a>(projects: &'a [Project], spec: &SortSpec) -> Vec<(Vec<SortValue>, &'a Project)> {
+        let enrichment = Enrichment::default();
+        let mut keyed: Vec<(Vec<SortValue>, &Project)> = projects
+            .iter()
+            .enumerate()
+            .map(|(rank, p)| (spec.key(p, rank, &enrichment), p))
+            .collect();
+        PaginationService::sort(&mut keyed, spec);
+        keyed
+    }
+
+    #[test]
+    fn test_cursor_pages_survive_inserts() {
+        let spec = SortSpec::parse("funding:desc").unwrap();
+        let projects: Vec<Project> = (0..5).map(|i| project(&format!("p{}", i), i as f64 * 10.0)).collect();
+        let keyed = sorted(&projects, &spec);
+        let first = PaginationService::paginate(&keyed, &spec, None, Some(2), None).unwrap();
+        assert_eq!(ids(&first), ["p4", "p3"]);
+        assert!(first.prev_cursor.is_none());
+        let next = first.next_cursor.unwrap();
+
+        // A project funded above the boundary does not shift the next page
+        let mut grown = projects.clone();
+        grown.push(project("p9", 35.0));
+        let keyed = sorted(&grown, &spec);
+        let second = PaginationService::paginate(&keyed, &spec, None, Some(2), Some(&next)).unwrap();
+        assert_eq!(ids(&second), ["p2", "p1"]);
+
+        let back = PaginationService::paginate(&keyed, &spec, None, Some(2), second.prev_cursor.as_deref()).unwrap();
+        assert_eq!(ids(&back), ["p9", "p3"]);
+
+        let by_title = SortSpec::parse("title").unwrap();
+        assert!(PaginationService::paginate(&keyed, &by_title, None, Some(2), Some(&next)).is_err());
+    }
+
+    #[test]
+    fn test_default_listing_cursor_survives_inserts() {
+        let spec = SortSpec::from_filter(&ProjectFilter::default()).unwrap();
+        let projects: Vec<Project> = ["b", "c", "d", "e"].iter().map(|id| project(id, 10.0)).collect();
+        let first = PaginationService::paginate(&sorted(&projects, &spec), &spec, None, Some(2), None).unwrap();
+        assert_eq!(ids(&first), ["b", "c"]);
+
+        // A project inserted ahead of the boundary, first in load order too, between the requests
+        let mut grown = vec![project("a", 10.0)];
+        grown.extend(projects.iter().cloned());
+        let keyed = sorted(&grown, &spec);
+        let second = PaginationService::paginate(&keyed, &spec, None, Some(2), first.next_cursor.as_deref()).unwrap();
+        assert_eq!(ids(&second), ["d", "e"]);
+    }
+
+    #[test]
+    fn test_invalid_pages_are_rejected_not_panicking() {
+        let spec = SortSpec::parse("title").unwrap();
+        let projects = [project("a", 1.0)];
+        let keyed = sorted(&projects, &spec);
+
+        assert!(PaginationService::paginate(&keyed, &spec, Some(0), None, None).is_err());
+        assert!(PaginationService::paginate(&keyed, &spec, None, Some(MAX_PER_PAGE + 1), None).is_err());
+        assert!(PaginationService::paginate(&keyed, &spec, None, None, Some("not a cursor")).is_err());
+
+        let past_end = PaginationService::paginate(&keyed, &spec, Some(50), None, None).unwrap();
+        assert!(past_end.projects.is_empty());
+        assert!(past_end.next_cursor.is_none());
+    }
+}
@@ -5,6 +5,8 @@ use shared::{
 use lib_web::AppError;
 use std::collections::HashMap;
 
+use crate::Dataset;
+
 pub struct PredictorService;
 
 impl PredictorService {
@@ -76,15 +78,18 @@ impl PredictorService {
 
     /// Search for competitor projects
     pub async fn search_competitors(
-        projects: &[Project],
+        dataset: &Dataset,
         req: CompetitorSearchRequest,
     ) -> Result<CompetitorSearchResponse, AppError> {
         let limit = req.limit.unwrap_or(10);
+        let keywords = req.keywords.join(" ");
 
         // Find competitors
-        let competitors: Vec<CompetitorProject> = projects
+        let mut competitors: Vec<CompetitorProject> = dataset
+            .projects
             .iter()
-            .filter(|p| {
+            .enumerate()
+            .filter_map(|(position, p)| {
                 let matches_category = p
                     .category
                     .as_ref()
@@ -99,21 +104,25 @@ impl PredictorService {
                             .unwrap_or(false)
                 });
 
-                matches_category || matches_keywords
-            })
-            .take(limit)
-            .map(|p| CompetitorProject {
-                title: p.title.clone(),
-                funding_amount: p.get_funding_amount_numeric().unwrap_or(0.0),
-                category: p
-                    .category
-                    .clone()
-                    .unwrap_or_else(|| "Unknown".to_string()),
-                similarity_score: 0.75, // Placeholder
-                key_features: p.tags.clone(),
+                (matches_category || matches_keywords).then(|| CompetitorProject {
+                    title: p.title.clone(),
+                    funding_amount: p.get_funding_amount_numeric().unwrap_or(0.0),
+                    category: p
+                        .category
+                        .clone()
+                        .unwrap_or_else(|| "Unknown".to_string()),
+                    similarity_score: dataset
+                        .similarity
+                        .keyword_similarity(&keywords, matches_category, position),
+                    key_features: p.tags.clone(),
+                })
             })
             .collect();
 
+        // Most similar first
+        competitors.sort_by(|a, b| b.similarity_score.total_cmp(&a.similarity_score));
+        competitors.truncate(limit);
+
         let mut market_insights = HashMap::new();
         market_insights.insert(
             "total_competitors".to_string(),
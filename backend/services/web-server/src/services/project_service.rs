@@ -1,182 +1,385 @@
-use shared::{Project, ProjectFilter, ProjectSearchRequest, ProjectsResponse};
+use shared::{
+    BooleanFacet, Chain, Country, FacetCount, IntegrationStatus, Program, Project, ProjectCategory,
+    ProjectFacets, ProjectFilter, ProjectSearchRequest, ProjectType, ProjectsResponse, Quarter, Region,
+    SimilarProject, SimilarProjectsResponse,
+};
 use lib_web::AppError;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    repositories::ProjectRepository,
+    services::{
+        Enrichment, PaginationService, ProjectQuery, ProjectView, SearchHit, SearchIndex, SortSpec, SortValue,
+    },
+    Dataset,
+};
+
+/// `funding_tier` values, lowest first
+pub(crate) const FUNDING_TIERS: [&str; 4] = ["0-50k", "50k-100k", "100k-150k", "150k+"];
+
+/// `rounds` as an exact count ("2") or a minimum ("4+")
+pub(crate) fn parse_rounds(value: &str) -> Option<(usize, bool)> {
+    let value = value.trim();
+    match value.strip_suffix('+') {
+        Some(min) => min.trim().parse().ok().map(|n| (n, true)),
+        None => value.parse().ok().map(|n| (n, false)),
+    }
+}
 
-pub struct ProjectService;
+pub(crate) fn funding_tier(funding: f64) -> &'static str {
+    match funding {
+        f if f < 50000.0 => "0-50k",
+        f if f < 100000.0 => "50k-100k",
+        f if f < 150000.0 => "100k-150k",
+        _ => "150k+",
+    }
+}
 
-impl ProjectService {
-    /// List projects with filtering and pagination
-    pub async fn list_projects(
-        projects: &[Project],
-        filter: ProjectFilter,
-    ) -> Result<ProjectsResponse, AppError> {
-        let mut filtered: Vec<&Project> = projects.iter().collect();
+/// A filter dimension the listing reports option counts for
+#[derive(Debug, Clone, Copy)]
+enum Facet {
+    Category,
+    Country,
+    Program,
+    Quarter,
+    FundingTier,
+    Soroban,
+    Mainnet,
+}
 
-        // Apply filters
-        if let Some(category) = &filter.category {
-            filtered.retain(|p| {
-                p.category
-                    .as_ref()
-                    .map(|c| c.to_lowercase().contains(&category.to_lowercase()))
-                    .unwrap_or(false)
-            });
+impl Facet {
+    /// The filter without this facet's own selection
+    fn relax(&self, filter: &ProjectFilter) -> ProjectFilter {
+        let mut filter = filter.clone();
+        match self {
+            Self::Category => (filter.category, filter.categories) = (None, None),
+            Self::Country => (filter.country, filter.countries) = (None, None),
+            Self::Program => filter.programs = None,
+            Self::Quarter => (filter.quarter, filter.quarters) = (None, None),
+            Self::FundingTier => filter.funding_tier = None,
+            Self::Soroban => filter.soroban_only = None,
+            Self::Mainnet => filter.has_mainnet = None,
         }
+        filter
+    }
 
-        if let Some(project_type) = &filter.project_type {
-            filtered.retain(|p| {
-                p.project_type
-                    .as_ref()
-                    .map(|pt| pt.to_lowercase().contains(&project_type.to_lowercase()))
-                    .unwrap_or(false)
-            });
+    /// Filter values the project counts toward, spelled so the filter accepts them back
+    fn values(&self, project: &Project) -> Vec<String> {
+        let yes_no = |yes: bool| vec![if yes { "yes" } else { "no" }.to_string()];
+        match self {
+            Self::Category => match project.get_category() {
+                ProjectCategory::Other => project.category.iter().map(|c| c.trim().to_string()).collect(),
+                known => vec![known.as_str().to_string()],
+            },
+            Self::Country => project.country_name().map(str::to_string).into_iter().collect(),
+            Self::Program => project.parsed.programs.iter().map(|p| p.as_str().to_string()).collect(),
+            Self::Quarter => project.parsed.quarters.iter().map(Quarter::label).collect(),
+            Self::FundingTier => vec![funding_tier(project.get_funding_amount_numeric().unwrap_or(0.0)).to_string()],
+            Self::Soroban => yes_no(project.uses_soroban()),
+            Self::Mainnet => yes_no(project.is_mainnet()),
         }
+    }
+}
 
-        if let Some(true) = filter.soroban_only {
-            filtered.retain(|p| p.uses_soroban());
-        }
+pub struct ProjectService;
 
-        if let Some(min) = filter.min_funding {
-            filtered.retain(|p| {
-                p.get_funding_amount_numeric()
-                    .map(|amount| amount >= min)
-                    .unwrap_or(false)
-            });
-        }
+impl ProjectService {
+    /// Get a single project by id
+    pub fn get_project<'a>(dataset: &'a Dataset, id: &str) -> Result<&'a Project, AppError> {
+        dataset
+            .project(id)
+            .ok_or_else(|| AppError::NotFound("Project not found".to_string()))
+    }
 
-        if let Some(max) = filter.max_funding {
-            filtered.retain(|p| {
-                p.get_funding_amount_numeric()
-                    .map(|amount| amount <= max)
-                    .unwrap_or(false)
-            });
-        }
+    /// Projects most like the one with this id, by description and tags, category, type,
+    /// chains, Soroban use and country
+    pub async fn similar_projects(
+        dataset: &Dataset,
+        id: &str,
+        limit: Option<usize>,
+    ) -> Result<SimilarProjectsResponse, AppError> {
+        let position = dataset
+            .position(id)
+            .ok_or_else(|| AppError::NotFound("Project not found".to_string()))?;
+
+        let similar = dataset
+            .similarity
+            .similar(&dataset.projects, position, limit)
+            .into_iter()
+            .map(|(other, score, breakdown)| SimilarProject {
+                project: dataset.projects[other].clone(),
+                score,
+                breakdown,
+            })
+            .collect();
 
-        if let Some(query) = &filter.search_query {
-            let query_lower = query.to_lowercase();
-            filtered.retain(|p| {
-                p.title.to_lowercase().contains(&query_lower)
-                    || p.description
-                        .as_ref()
-                        .map(|d| d.to_lowercase().contains(&query_lower))
-                        .unwrap_or(false)
-            });
+        Ok(SimilarProjectsResponse { project_id: id.to_string(), similar })
+    }
+
+    /// Advanced filter projects; with a search query, results are ranked by relevance
+    /// unless `sort_by` asks for another order. A `q` query narrows the projects before
+    /// any other filter, so facets count within it. Projects are borrowed from the dataset,
+    /// so serialize the response while it is pinned. With `db`, listings PostgreSQL can
+    /// answer are filtered, sorted and paged there.
+    pub async fn filter_projects<'a>(
+        dataset: &'a Dataset,
+        filter: ProjectFilter,
+        db: Option<&PgPool>,
+    ) -> Result<ProjectsResponse<ProjectView<'a>>, AppError> {
+        // Sort by `sort`, falling back to relevance when searching and title otherwise
+        let spec = SortSpec::from_filter(&filter)?;
+        if let Some(db) = db.filter(|_| ProjectRepository::supports(&filter, &spec)) {
+            return Self::query_projects(dataset, filter, &spec, db).await;
         }
 
+        let (response, _) = Self::list_projects(dataset, filter, &spec)?;
+        Ok(response)
+    }
+
+    /// `filter_projects` on the dataset, with the ids of every project the filter matches
+    /// across all pages, from the same pass over the projects
+    pub(crate) fn filter_projects_with_ids<'a>(
+        dataset: &'a Dataset,
+        filter: ProjectFilter,
+    ) -> Result<(ProjectsResponse<ProjectView<'a>>, Vec<String>), AppError> {
+        let spec = SortSpec::from_filter(&filter)?;
+        let (response, matching) = Self::list_projects(dataset, filter, &spec)?;
+        Ok((response, matching.into_iter().map(|p| p.id.clone()).collect()))
+    }
+
+    /// One page of the dataset's projects matching the filter, and all of them in listing order
+    fn list_projects<'a>(
+        dataset: &'a Dataset,
+        filter: ProjectFilter,
+        spec: &SortSpec,
+    ) -> Result<(ProjectsResponse<ProjectView<'a>>, Vec<&'a Project>), AppError> {
+        let hits = filter.search_query.as_deref().and_then(|q| dataset.search.search(q));
+        let (candidates, filtered) = Self::select(dataset, &filter, hits.as_deref())?;
         let total = filtered.len();
-        let page = filter.page.unwrap_or(1);
-        let per_page = filter.per_page.unwrap_or(20);
-        let total_pages = (total + per_page - 1) / per_page;
+        let mut keyed: Vec<(Vec<SortValue>, &Project)> = filtered
+            .into_iter()
+            .enumerate()
+            .map(|(rank, p)| (spec.key(p, rank, &dataset.enrichment), p))
+            .collect();
+        PaginationService::sort(&mut keyed, spec);
+        let page = PaginationService::paginate(&keyed, spec, filter.page, filter.per_page, filter.cursor.as_deref())?;
+        let paginated_projects: Vec<ProjectView> = page.projects.into_iter().map(ProjectView::new).collect();
+
+        let response = ProjectsResponse {
+            projects: Self::with_search_matches(dataset, paginated_projects, hits.as_deref()),
+            total,
+            page: page.page,
+            per_page: page.per_page,
+            total_pages: page.total_pages,
+            next_cursor: page.next_cursor,
+            prev_cursor: page.prev_cursor,
+            facets: Some(Self::facets(&candidates, &filter, &dataset.enrichment)),
+        };
+        Ok((response, keyed.into_iter().map(|(_, p)| p).collect()))
+    }
 
-        // Paginate
+    /// `filter_projects` with the page and total from the `projects` table. Its rows are the
+    /// dataset's projects, so they are shown and counted in facets from the dataset.
+    async fn query_projects<'a>(
+        dataset: &'a Dataset,
+        filter: ProjectFilter,
+        spec: &SortSpec,
+        db: &PgPool,
+    ) -> Result<ProjectsResponse<ProjectView<'a>>, AppError> {
+        Self::validate_filter(&filter)?;
+        let (page, per_page) = PaginationService::validate(filter.page, filter.per_page)?;
+        let (ids, total) = ProjectRepository::find_filtered_ids(db, &filter, spec).await?;
+
+        // Rows imported after the dataset was loaded only show up once it reloads
+        let projects: Vec<&Project> = ids.iter().filter_map(|id| dataset.project(id)).collect();
         let start = (page - 1) * per_page;
-        let end = (start + per_page).min(total);
-        let paginated: Vec<Project> = filtered[start..end].iter().map(|&p| p.clone()).collect();
+        let cursor = |project: &Project, before| {
+            PaginationService::cursor(spec, &spec.key(project, 0, &dataset.enrichment), project, before)
+        };
+        let candidates: Vec<&Project> = dataset.projects.iter().collect();
 
         Ok(ProjectsResponse {
-            projects: paginated,
+            next_cursor: projects.last().filter(|_| start + projects.len() < total).map(|p| cursor(p, false)),
+            prev_cursor: projects.first().filter(|_| start > 0).map(|p| cursor(p, true)),
+            projects: projects.into_iter().map(ProjectView::new).collect(),
             total,
             page,
             per_page,
-            total_pages,
+            total_pages: total.div_ceil(per_page),
+            facets: Some(Self::facets(&candidates, &filter, &dataset.enrichment)),
         })
     }
 
-    /// Get a single project by title
-    pub async fn get_project(
-        projects: &[Project],
-        id: &str,
-    ) -> Result<Project, AppError> {
-        projects
-            .iter()
-            .find(|p| p.title == id)
-            .cloned()
-            .ok_or_else(|| AppError::NotFound("Project not found".to_string()))
+    /// Every project the filter matches, ignoring pagination
+    pub(crate) fn matching<'a>(dataset: &'a Dataset, filter: &ProjectFilter) -> Result<Vec<&'a Project>, AppError> {
+        let hits = filter.search_query.as_deref().and_then(|q| dataset.search.search(q));
+        let (_, filtered) = Self::select(dataset, filter, hits.as_deref())?;
+        Ok(filtered)
     }
 
-    /// Advanced filter projects
-    pub async fn filter_projects(
-        projects: &[Project],
-        filter: ProjectFilter,
-    ) -> Result<ProjectsResponse, AppError> {
-        let mut filtered: Vec<&Project> = projects
+    /// Projects passing `q`, which facets count within, and those also passing every other
+    /// filter; in relevance order when searching and dataset order otherwise
+    fn select<'a>(
+        dataset: &'a Dataset,
+        filter: &ProjectFilter,
+        hits: Option<&[SearchHit]>,
+    ) -> Result<(Vec<&'a Project>, Vec<&'a Project>), AppError> {
+        Self::validate_filter(filter)?;
+        let query = Self::parse_query(filter)?;
+        let matcher = query.as_ref().map(|query| query.matcher(dataset));
+
+        let candidates: Vec<&Project> = match hits {
+            Some(hits) => hits.iter().map(|hit| &dataset.projects[hit.position]).collect(),
+            None => dataset.projects.iter().collect(),
+        };
+        let candidates: Vec<&Project> = candidates
+            .into_iter()
+            .filter(|p| matcher.as_ref().is_none_or(|m| m.matches(p)))
+            .collect();
+        let filtered: Vec<&Project> = candidates
             .iter()
-            .filter(|p| Self::matches_filter(p, &filter))
+            .copied()
+            .filter(|p| Self::matches_filter(p, filter, &dataset.enrichment))
             .collect();
 
-        // Apply sorting
-        if let Some(sort_by) = &filter.sort_by {
-            match sort_by.as_str() {
-                "funding_desc" => filtered.sort_by(|a, b| {
-                    b.get_funding_amount_numeric().unwrap_or(0.0)
-                        .partial_cmp(&a.get_funding_amount_numeric().unwrap_or(0.0))
-                        .unwrap()
-                }),
-                "funding_asc" => filtered.sort_by(|a, b| {
-                    a.get_funding_amount_numeric().unwrap_or(0.0)
-                        .partial_cmp(&b.get_funding_amount_numeric().unwrap_or(0.0))
-                        .unwrap()
-                }),
-                "name_asc" => filtered.sort_by(|a, b| a.title.cmp(&b.title)),
-                "name_desc" => filtered.sort_by(|a, b| b.title.cmp(&a.title)),
-                _ => {} // Default ordering
-            }
-        }
-
-        // Pagination
-        let page = filter.page.unwrap_or(1);
-        let per_page = filter.per_page.unwrap_or(20);
-        let total = filtered.len();
-        let total_pages = (total as f64 / per_page as f64).ceil() as usize;
+        Ok((candidates, filtered))
+    }
 
-        let start = (page - 1) * per_page;
-        let end = (start + per_page).min(total);
+    /// Option counts over the candidates matching every constraint except the facet's own
+    fn facets(candidates: &[&Project], filter: &ProjectFilter, enrichment: &Enrichment) -> ProjectFacets {
+        let counts = |facet: Facet| -> HashMap<String, usize> {
+            let relaxed = facet.relax(filter);
+            let mut counts = HashMap::new();
+            for project in candidates.iter().filter(|p| Self::matches_filter(p, &relaxed, enrichment)) {
+                for value in facet.values(project) {
+                    *counts.entry(value).or_insert(0) += 1;
+                }
+            }
+            counts
+        };
+        let by_count = |facet: Facet| -> Vec<FacetCount> {
+            let mut values: Vec<FacetCount> = counts(facet)
+                .into_iter()
+                .map(|(value, count)| FacetCount { value, count })
+                .collect();
+            values.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+            values
+        };
+        let yes_no = |facet: Facet| {
+            let counts = counts(facet);
+            BooleanFacet {
+                yes: counts.get("yes").copied().unwrap_or(0),
+                no: counts.get("no").copied().unwrap_or(0),
+            }
+        };
+
+        let mut quarters = by_count(Facet::Quarter);
+        quarters.sort_by_key(|c| Quarter::parse(&c.value));
+        let tiers = counts(Facet::FundingTier);
+
+        ProjectFacets {
+            categories: by_count(Facet::Category),
+            countries: by_count(Facet::Country),
+            programs: by_count(Facet::Program),
+            quarters,
+            funding_tiers: FUNDING_TIERS
+                .iter()
+                .map(|tier| FacetCount {
+                    value: tier.to_string(),
+                    count: tiers.get(*tier).copied().unwrap_or(0),
+                })
+                .collect(),
+            soroban: yes_no(Facet::Soroban),
+            mainnet: yes_no(Facet::Mainnet),
+        }
+    }
 
-        let paginated_projects: Vec<Project> = filtered[start..end]
+    /// Attach each project's score and snippet when the request searched
+    fn with_search_matches<'a>(
+        dataset: &Dataset,
+        mut projects: Vec<ProjectView<'a>>,
+        hits: Option<&[SearchHit]>,
+    ) -> Vec<ProjectView<'a>> {
+        let Some(hits) = hits else {
+            return projects;
+        };
+        let by_id: HashMap<&str, &SearchHit> = hits
             .iter()
-            .map(|&p| p.clone())
+            .map(|hit| (dataset.projects[hit.position].id.as_str(), hit))
             .collect();
 
-        Ok(ProjectsResponse {
-            projects: paginated_projects,
-            total,
-            page,
-            per_page,
-            total_pages,
-        })
+        for view in projects.iter_mut() {
+            if let Some(hit) = by_id.get(view.project.id.as_str()) {
+                view.search_match = Some(SearchIndex::search_match(hit, view.project, &dataset.enrichment));
+            }
+        }
+        projects
     }
 
-    fn matches_filter(project: &Project, filter: &ProjectFilter) -> bool {
-        // Text search
-        if let Some(query) = &filter.search_query {
-            let query_lower = query.to_lowercase();
-            let matches = project.title.to_lowercase().contains(&query_lower)
-                || project.description.as_ref().map(|d| d.to_lowercase().contains(&query_lower)).unwrap_or(false)
-                || project.company.as_ref().map(|c| c.to_lowercase().contains(&query_lower)).unwrap_or(false);
-            if !matches {
-                return false;
+    /// The filter's `q` query, parsed
+    pub(crate) fn parse_query(filter: &ProjectFilter) -> Result<Option<ProjectQuery>, AppError> {
+        filter
+            .q
+            .as_deref()
+            .map(ProjectQuery::parse)
+            .transpose()
+            .map_err(AppError::InvalidQuery)
+    }
+
+    /// Reject filter values that would otherwise silently match nothing
+    pub(crate) fn validate_filter(filter: &ProjectFilter) -> Result<(), AppError> {
+        SortSpec::from_filter(filter)?;
+        if let Some(year) = &filter.year {
+            if year.trim().parse::<u16>().is_err() {
+                return Err(AppError::BadRequest(format!("year must be a year such as 2023, got `{}`", year)));
+            }
+        }
+        if let Some(rounds) = &filter.rounds {
+            if parse_rounds(rounds).is_none() {
+                return Err(AppError::BadRequest(format!(
+                    "rounds must be a count such as 2 or a minimum such as 4+, got `{}`",
+                    rounds
+                )));
             }
         }
+        if let Some(tier) = &filter.funding_tier {
+            if !FUNDING_TIERS.contains(&tier.as_str()) {
+                return Err(AppError::BadRequest(format!(
+                    "funding_tier must be one of {}, got `{}`",
+                    FUNDING_TIERS.join(", "),
+                    tier
+                )));
+            }
+        }
+        let quarters = filter.quarters.iter().flatten().chain(filter.quarter.iter());
+        if let Some(quarter) = quarters.into_iter().find(|q| Quarter::parse(q).is_none()) {
+            return Err(AppError::BadRequest(format!("`{}` is not a quarter such as Q2 2023", quarter)));
+        }
+        Ok(())
+    }
+
+    /// Whether the project passes every filter field; the one place filter semantics live
+    pub(crate) fn matches_filter(project: &Project, filter: &ProjectFilter, enrichment: &Enrichment) -> bool {
+        // Text search runs against the dataset's search index before filtering
 
         // Category filter (single or multiple)
         if let Some(categories) = &filter.categories {
             if !categories.is_empty() {
-                let matches = project.category.as_ref().map(|c| {
-                    categories.iter().any(|cat| c.to_lowercase().contains(&cat.to_lowercase()))
-                }).unwrap_or(false);
+                let matches = categories.iter().any(|cat| Self::matches_category(project, cat));
                 if !matches {
                     return false;
                 }
             }
         } else if let Some(category) = &filter.category {
-            let matches = project.category.as_ref().map(|c| c.to_lowercase().contains(&category.to_lowercase())).unwrap_or(false);
-            if !matches {
+            if !Self::matches_category(project, category) {
                 return false;
             }
         }
 
         // Project type filter
         if let Some(ptype) = &filter.project_type {
-            let matches = project.project_type.as_ref().map(|pt| pt.to_lowercase() == ptype.to_lowercase()).unwrap_or(false);
-            if !matches {
+            if !Self::matches_project_type(project, ptype) {
                 return false;
             }
         }
@@ -196,14 +399,7 @@ impl ProjectService {
         // Funding tier
         if let Some(tier) = &filter.funding_tier {
             let funding = project.get_funding_amount_numeric().unwrap_or(0.0);
-            let matches = match tier.as_str() {
-                "0-50k" => funding < 50000.0,
-                "50k-100k" => funding >= 50000.0 && funding < 100000.0,
-                "100k-150k" => funding >= 100000.0 && funding < 150000.0,
-                "150k+" => funding >= 150000.0,
-                _ => true,
-            };
-            if !matches {
+            if funding_tier(funding) != tier {
                 return false;
             }
         }
@@ -215,7 +411,7 @@ impl ProjectService {
             }
         }
         if let Some(stellar) = filter.stellar_only {
-            if stellar && project.other_chains.is_some() {
+            if stellar && project.is_multichain() {
                 return false;
             }
         }
@@ -225,35 +421,44 @@ impl ProjectService {
             }
         }
         if let Some(has_mn) = filter.has_mainnet {
-            if has_mn {
-                let is_mainnet = project.integration_status.as_ref().map(|s| s.to_lowercase().contains("mainnet")).unwrap_or(false);
-                if !is_mainnet {
-                    return false;
-                }
+            if has_mn && !project.is_mainnet() {
+                return false;
             }
         }
         if let Some(is_os) = filter.is_open_source {
-            if is_os {
-                let has_os = project.open_source.as_ref().map(|o| o.to_lowercase() == "yes" || o == "true").unwrap_or(false);
-                if !has_os {
-                    return false;
-                }
+            if is_os && !project.is_open_source() {
+                return false;
+            }
+        }
+        if let Some(chains) = filter.other_chains.as_ref().filter(|c| !c.is_empty()) {
+            let matches = chains.iter().map(|c| Chain::parse(c)).any(|chain| {
+                project
+                    .parsed
+                    .chains
+                    .iter()
+                    .any(|c| c.as_str().eq_ignore_ascii_case(chain.as_str()))
+            });
+            if !matches {
+                return false;
             }
         }
 
         // Geographic filters
         if let Some(countries) = &filter.countries {
-            if !countries.is_empty() {
-                let matches = project.country.as_ref().map(|c| {
-                    countries.iter().any(|country| c.to_lowercase().contains(&country.to_lowercase()))
-                }).unwrap_or(false);
-                if !matches {
-                    return false;
-                }
+            if !countries.is_empty() && !countries.iter().any(|c| Self::matches_country(project, c)) {
+                return false;
             }
         } else if let Some(country) = &filter.country {
-            let matches = project.country.as_ref().map(|c| c.to_lowercase().contains(&country.to_lowercase())).unwrap_or(false);
-            if !matches {
+            if !Self::matches_country(project, country) {
+                return false;
+            }
+        }
+        if let Some(regions) = &filter.regions {
+            if !regions.is_empty() && !regions.iter().any(|r| Self::matches_region(project, r)) {
+                return false;
+            }
+        } else if let Some(region) = &filter.region {
+            if !Self::matches_region(project, region) {
                 return false;
             }
         }
@@ -261,9 +466,10 @@ impl ProjectService {
         // Programs filter
         if let Some(programs) = &filter.programs {
             if !programs.is_empty() {
-                let matches = project.programs.as_ref().map(|p| {
-                    programs.iter().any(|prog| p.to_lowercase().contains(&prog.to_lowercase()))
-                }).unwrap_or(false);
+                let matches = programs
+                    .iter()
+                    .map(|prog| Program::parse(prog))
+                    .any(|prog| project.parsed.programs.contains(&prog));
                 if !matches {
                     return false;
                 }
@@ -272,10 +478,13 @@ impl ProjectService {
 
         // Rounds filter
         if let Some(min_rounds) = filter.min_rounds {
-            let rounds_count = project.rounds.as_ref()
-                .and_then(|r| r.split(',').count().try_into().ok())
-                .unwrap_or(0u32);
-            if rounds_count < min_rounds {
+            if (project.parsed.rounds.len() as u32) < min_rounds {
+                return false;
+            }
+        }
+        if let Some((count, or_more)) = filter.rounds.as_deref().and_then(parse_rounds) {
+            let rounds = project.parsed.rounds.len();
+            if rounds < count || (!or_more && rounds > count) {
                 return false;
             }
         }
@@ -288,6 +497,20 @@ impl ProjectService {
             }
         }
 
+        // Known stages compare normalized; anything else matches the raw value as a substring
+        if let Some(status) = &filter.integration_status {
+            let matches = match IntegrationStatus::parse(status) {
+                Some(known) => project.parsed.integration_status == Some(known),
+                None => project
+                    .integration_status
+                    .as_ref()
+                    .is_some_and(|s| s.to_lowercase().contains(&status.trim().to_lowercase())),
+            };
+            if !matches {
+                return false;
+            }
+        }
+
         // Social presence filters
         if let Some(has_web) = filter.has_website {
             if has_web && project.website.is_none() {
@@ -299,55 +522,250 @@ impl ProjectService {
                 return false;
             }
         }
+        // Twitter and Discord come from the enriched social links
+        let social = enrichment.social.get(&project.id);
+        let has_link = |link: Option<&String>| link.is_some_and(|l| !l.trim().is_empty());
+        if filter.has_twitter == Some(true) && !has_link(social.and_then(|s| s.twitter.as_ref())) {
+            return false;
+        }
+        if filter.has_discord == Some(true) && !has_link(social.and_then(|s| s.discord.as_ref())) {
+            return false;
+        }
 
         // Quarter/Year filters
+        let funded_in = |quarters: &[String]| {
+            quarters
+                .iter()
+                .filter_map(|quarter| Quarter::parse(quarter))
+                .any(|quarter| project.parsed.quarters.contains(&quarter))
+        };
         if let Some(quarters) = &filter.quarters {
-            if !quarters.is_empty() {
-                let matches = project.quarters.as_ref().map(|q| {
-                    quarters.iter().any(|quarter| q.contains(quarter))
-                }).unwrap_or(false);
-                if !matches {
-                    return false;
-                }
+            if !quarters.is_empty() && !funded_in(quarters) {
+                return false;
+            }
+        } else if let Some(quarter) = &filter.quarter {
+            if !funded_in(std::slice::from_ref(quarter)) {
+                return false;
+            }
+        }
+        if let Some(year) = filter.year.as_deref().and_then(|y| y.trim().parse::<u16>().ok()) {
+            if !project.parsed.quarters.iter().any(|q| q.year() == year) {
+                return false;
             }
         }
 
         true
     }
 
+    /// ISO codes, names and aliases compare by country; anything else matches as a substring
+    pub(crate) fn matches_country(project: &Project, country: &str) -> bool {
+        match Country::lookup(country) {
+            Some(country) => project.parsed.country == Some(country),
+            None => project
+                .country
+                .as_ref()
+                .is_some_and(|c| c.to_lowercase().contains(&country.to_lowercase())),
+        }
+    }
+
+    /// Continents and subregions match where the project is based; anything else
+    /// matches the project's own `regions` text, such as "LATAM"
+    pub(crate) fn matches_region(project: &Project, region: &str) -> bool {
+        match Region::parse(region) {
+            Some(region) => project.parsed.country.is_some_and(|c| region.contains(c)),
+            None => project
+                .regions
+                .as_ref()
+                .is_some_and(|r| r.to_lowercase().contains(&region.to_lowercase())),
+        }
+    }
+
+    /// Known categories compare normalized; anything else matches as a substring
+    pub fn matches_category(project: &Project, category: &str) -> bool {
+        match ProjectCategory::from_str(category) {
+            ProjectCategory::Other => project
+                .category
+                .as_ref()
+                .is_some_and(|c| c.to_lowercase().contains(&category.to_lowercase())),
+            known => project.get_category() == known,
+        }
+    }
+
+    /// Types in the taxonomy compare normalized; anything else compares the raw value
+    pub(crate) fn matches_project_type(project: &Project, project_type: &str) -> bool {
+        match ProjectType::parse(project_type) {
+            Some(known) => project.parsed.project_type == Some(known),
+            None => project
+                .project_type
+                .as_ref()
+                .is_some_and(|pt| pt.eq_ignore_ascii_case(project_type.trim())),
+        }
+    }
+
     /// Search projects (legacy)
-    pub async fn search_projects(
-        projects: &[Project],
+    pub async fn search_projects<'a>(
+        dataset: &'a Dataset,
         req: ProjectSearchRequest,
-    ) -> Result<Vec<Project>, AppError> {
-        let query_lower = req.query.to_lowercase();
+    ) -> Result<Vec<ProjectView<'a>>, AppError> {
         let limit = req.limit.unwrap_or(10);
-
-        let results: Vec<&Project> = projects
-            .iter()
+        let hits = dataset.search.search(&req.query);
+        if let Some(filter) = &req.filter {
+            Self::validate_filter(filter)?;
+        }
+        let query = req.filter.as_ref().map(Self::parse_query).transpose()?.flatten();
+        let matcher = query.as_ref().map(|query| query.matcher(dataset));
+
+        // The filter's own `search_query` narrows the results as it does on `/api/projects`
+        let filter_hits: Option<HashSet<usize>> = req
+            .filter
+            .as_ref()
+            .and_then(|filter| filter.search_query.as_deref())
+            .and_then(|q| dataset.search.search(q))
+            .map(|hits| hits.iter().map(|hit| hit.position).collect());
+
+        let positions: Vec<usize> = match &hits {
+            Some(hits) => hits.iter().map(|hit| hit.position).collect(),
+            None => (0..dataset.projects.len()).collect(),
+        };
+        let results: Vec<ProjectView> = positions
+            .into_iter()
+            .filter(|position| filter_hits.as_ref().is_none_or(|matched| matched.contains(position)))
+            .map(|position| &dataset.projects[position])
+            .filter(|p| {
+                req.category
+                    .as_ref()
+                    .map(|cat| Self::matches_category(p, cat))
+                    .unwrap_or(true)
+            })
             .filter(|p| {
-                let matches_query = p.title.to_lowercase().contains(&query_lower)
-                    || p.description
-                        .as_ref()
-                        .map(|d| d.to_lowercase().contains(&query_lower))
-                        .unwrap_or(false);
-
-                let matches_category = req
-                    .category
+                req.filter
                     .as_ref()
-                    .map(|cat| {
-                        p.category
-                            .as_ref()
-                            .map(|c| c.to_lowercase().contains(&cat.to_lowercase()))
-                            .unwrap_or(false)
-                    })
-                    .unwrap_or(true);
-
-                matches_query && matches_category
+                    .is_none_or(|filter| Self::matches_filter(p, filter, &dataset.enrichment))
             })
+            .filter(|p| matcher.as_ref().is_none_or(|m| m.matches(p)))
             .take(limit)
+            .map(ProjectView::new)
             .collect();
 
-        Ok(results.into_iter().cloned().collect())
+        Ok(Self::with_search_matches(dataset, results, hits.as_deref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn project(title: &str, category: &str, country: &str, funding: f64, soroban: bool) -> Project {
+        test_support::project(json!({
+            "title": title,
+            "category": category,
+            "country": country,
+            "total_awarded": funding,
+            "soroban": soroban,
+        }))
+    }
+
+    #[test]
+    fn test_facets_ignore_their_own_selection() {
+        let projects = [
+            project("Lendy", "DeFi", "US", 40000.0, true),
+            project("Swapper", "DeFi", "Kenya", 120000.0, false),
+            project("Toolbox", "Developer Tooling", "United States", 60000.0, true),
+        ];
+        let candidates: Vec<&Project> = projects.iter().collect();
+        let filter = ProjectFilter {
+            categories: Some(vec!["DeFi".to_string()]),
+            countries: Some(vec!["USA".to_string()]),
+            ..ProjectFilter::default()
+        };
+
+        let facets = ProjectService::facets(&candidates, &filter, &Enrichment::default());
+        let counts = |facet: &[FacetCount]| -> Vec<(String, usize)> {
+            facet.iter().map(|c| (c.value.clone(), c.count)).collect()
+        };
+
+        // Categories among US projects, countries among DeFi projects
+        assert_eq!(
+            counts(&facets.categories),
+            [("Developer Tooling".to_string(), 1), ("Financial Protocols".to_string(), 1)]
+        );
+        assert_eq!(
+            counts(&facets.countries),
+            [("Kenya".to_string(), 1), ("United States".to_string(), 1)]
+        );
+        // Everything else counts the one project matching both selections
+        assert_eq!(facets.funding_tiers[0].count, 1);
+        assert_eq!(facets.funding_tiers[2].count, 0);
+        assert_eq!((facets.soroban.yes, facets.soroban.no), (1, 0));
+    }
+
+    #[test]
+    fn test_filter_honors_chains_rounds_years_and_social_links() {
+        let project = test_support::project(json!({
+            "title": "Bridgy",
+            "other_chains": "Ethereum, Solana",
+            "integration_status": "Live on Mainnet",
+            "rounds": "SCF #20, SCF #24",
+            "quarters": "Q2 '23, Q1 '24",
+        }));
+
+        let mut enrichment = Enrichment::default();
+        enrichment.social.insert(
+            project.id.clone(),
+            shared::SocialLinks {
+                discord: None,
+                twitter: Some("https://x.com/bridgy".to_string()),
+                linkedin: None,
+                medium: None,
+                video: None,
+                pitch_deck: None,
+                social_score: 0.5,
+            },
+        );
+        let matches = |filter: ProjectFilter| ProjectService::matches_filter(&project, &filter, &enrichment);
+
+        assert!(matches(ProjectFilter { other_chains: Some(vec!["eth".into()]), ..Default::default() }));
+        assert!(!matches(ProjectFilter { other_chains: Some(vec!["polygon".into()]), ..Default::default() }));
+        assert!(matches(ProjectFilter { integration_status: Some("mainnet".into()), ..Default::default() }));
+        assert!(!matches(ProjectFilter { integration_status: Some("testnet".into()), ..Default::default() }));
+        assert!(matches(ProjectFilter { rounds: Some("2".into()), ..Default::default() }));
+        assert!(!matches(ProjectFilter { rounds: Some("3+".into()), ..Default::default() }));
+        assert!(matches(ProjectFilter { quarter: Some("2024-Q1".into()), ..Default::default() }));
+        assert!(matches(ProjectFilter { year: Some("2023".into()), ..Default::default() }));
+        assert!(!matches(ProjectFilter { year: Some("2022".into()), ..Default::default() }));
+        assert!(matches(ProjectFilter { has_twitter: Some(true), ..Default::default() }));
+        assert!(!matches(ProjectFilter { has_discord: Some(true), ..Default::default() }));
+
+        assert!(ProjectService::validate_filter(&ProjectFilter { year: Some("recent".into()), ..Default::default() }).is_err());
+        assert!(ProjectService::validate_filter(&ProjectFilter { rounds: Some("many".into()), ..Default::default() }).is_err());
+        assert!(matches(ProjectFilter { funding_tier: Some("0-50k".into()), ..Default::default() }));
+        let error = ProjectService::validate_filter(&ProjectFilter { funding_tier: Some("huge".into()), ..Default::default() });
+        assert!(matches!(error, Err(AppError::BadRequest(message)) if message.contains("0-50k, 50k-100k, 100k-150k, 150k+")));
+    }
+
+    #[tokio::test]
+    async fn test_search_honors_the_filter_search_query() {
+        let projects = vec![
+            project("Stellar Lend", "DeFi", "US", 40000.0, true),
+            project("Stellar Swap", "DeFi", "Kenya", 120000.0, false),
+            project("Lend Tools", "Developer Tooling", "US", 60000.0, true),
+        ];
+        let dataset = Dataset::new(projects, shared::DatasetLoadReport::new("test"), String::new());
+        let search = |filter: Option<ProjectFilter>| ProjectSearchRequest {
+            query: "stellar".to_string(),
+            category: None,
+            limit: None,
+            filter,
+        };
+        let titles = |views: Vec<ProjectView>| -> Vec<String> { views.iter().map(|v| v.project.title.clone()).collect() };
+
+        let all = ProjectService::search_projects(&dataset, search(None)).await.unwrap();
+        assert_eq!(all.len(), 2);
+
+        let filter = ProjectFilter { search_query: Some("lend".to_string()), ..Default::default() };
+        let narrowed = ProjectService::search_projects(&dataset, search(Some(filter))).await.unwrap();
+        assert_eq!(titles(narrowed), ["Stellar Lend"]);
     }
 }
@@ -0,0 +1,370 @@
+use lib_web::AppError;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use shared::{Project, ProjectEnrichment, SearchMatch};
+
+/// Description characters a summary's `excerpt` keeps at most
+const EXCERPT_CHARS: usize = 160;
+
+/// A project field `fields=` can select
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectField {
+    Id,
+    Title,
+    Type,
+    Company,
+    Country,
+    Description,
+    Category,
+    TotalAwarded,
+    Programs,
+    Rounds,
+    Quarters,
+    IntegrationStatus,
+    OpenSource,
+    Website,
+    Github,
+    Soroban,
+    OtherChains,
+    Regions,
+    Traction,
+    Tags,
+    FundingDetails,
+    Status,
+    AnnouncementLink,
+    /// Start of the description; see `excerpt`
+    Excerpt,
+}
+
+impl ProjectField {
+    /// Every stored field, in the order `Project` serializes them
+    const ALL: [Self; 23] = [
+        Self::Id,
+        Self::Title,
+        Self::Type,
+        Self::Company,
+        Self::Country,
+        Self::Description,
+        Self::Category,
+        Self::TotalAwarded,
+        Self::Programs,
+        Self::Rounds,
+        Self::Quarters,
+        Self::IntegrationStatus,
+        Self::OpenSource,
+        Self::Website,
+        Self::Github,
+        Self::Soroban,
+        Self::OtherChains,
+        Self::Regions,
+        Self::Traction,
+        Self::Tags,
+        Self::FundingDetails,
+        Self::Status,
+        Self::AnnouncementLink,
+    ];
+
+    /// `fields=summary`; the fields of `ProjectSummary`
+    const SUMMARY: [Self; 10] = [
+        Self::Id,
+        Self::Title,
+        Self::Type,
+        Self::Country,
+        Self::Category,
+        Self::TotalAwarded,
+        Self::IntegrationStatus,
+        Self::Soroban,
+        Self::Tags,
+        Self::Excerpt,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Type => "type",
+            Self::Company => "company",
+            Self::Country => "country",
+            Self::Description => "description",
+            Self::Category => "category",
+            Self::TotalAwarded => "total_awarded",
+            Self::Programs => "programs",
+            Self::Rounds => "rounds",
+            Self::Quarters => "quarters",
+            Self::IntegrationStatus => "integration_status",
+            Self::OpenSource => "open_source",
+            Self::Website => "website",
+            Self::Github => "github",
+            Self::Soroban => "soroban",
+            Self::OtherChains => "other_chains",
+            Self::Regions => "regions",
+            Self::Traction => "traction",
+            Self::Tags => "tags",
+            Self::FundingDetails => "funding_details",
+            Self::Status => "status",
+            Self::AnnouncementLink => "announcement_link",
+            Self::Excerpt => "excerpt",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "project_type" => Some(Self::Type),
+            name => Self::ALL.into_iter().chain([Self::Excerpt]).find(|field| field.name() == name),
+        }
+    }
+
+    /// Write this field of `project` the way `Project` serializes it
+    fn serialize_entry<M: SerializeMap>(&self, project: &Project, map: &mut M) -> Result<(), M::Error> {
+        let name = self.name();
+        match self {
+            Self::Id => map.serialize_entry(name, &project.id),
+            Self::Title => map.serialize_entry(name, &project.title),
+            Self::Type => map.serialize_entry(name, &project.project_type),
+            Self::Company => map.serialize_entry(name, &project.company),
+            Self::Country => map.serialize_entry(name, &project.country),
+            Self::Description => map.serialize_entry(name, &project.description),
+            Self::Category => map.serialize_entry(name, &project.category),
+            Self::TotalAwarded => map.serialize_entry(name, &project.total_awarded),
+            Self::Programs => map.serialize_entry(name, &project.programs),
+            Self::Rounds => map.serialize_entry(name, &project.rounds),
+            Self::Quarters => map.serialize_entry(name, &project.quarters),
+            Self::IntegrationStatus => map.serialize_entry(name, &project.integration_status),
+            Self::OpenSource => map.serialize_entry(name, &project.open_source),
+            Self::Website => map.serialize_entry(name, &project.website),
+            Self::Github => map.serialize_entry(name, &project.github),
+            Self::Soroban => map.serialize_entry(name, &project.soroban),
+            Self::OtherChains => map.serialize_entry(name, &project.other_chains),
+            Self::Regions => map.serialize_entry(name, &project.regions),
+            Self::Traction => map.serialize_entry(name, &project.traction),
+            Self::Tags => map.serialize_entry(name, &project.tags),
+            Self::FundingDetails => Self::serialize_present(map, name, &project.funding_details),
+            Self::Status => Self::serialize_present(map, name, &project.status),
+            Self::AnnouncementLink => Self::serialize_present(map, name, &project.announcement_link),
+            Self::Excerpt => map.serialize_entry(name, &project.description.as_deref().and_then(Excerpt::of)),
+        }
+    }
+
+    /// Fields `Project` leaves out when empty
+    fn serialize_present<M: SerializeMap>(map: &mut M, name: &str, value: &Option<String>) -> Result<(), M::Error> {
+        match value {
+            Some(value) => map.serialize_entry(name, value),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The opening of a description, at most `EXCERPT_CHARS` characters, cut at a word and
+/// ending in "…" when shortened; borrowed from the description
+struct Excerpt<'a> {
+    text: &'a str,
+    shortened: bool,
+}
+
+impl<'a> Excerpt<'a> {
+    fn of(description: &'a str) -> Option<Self> {
+        let description = description.trim();
+        if description.is_empty() {
+            return None;
+        }
+        let Some((limit, _)) = description.char_indices().nth(EXCERPT_CHARS) else {
+            return Some(Self { text: description, shortened: false });
+        };
+
+        let head = &description[..limit];
+        let text = match head.rfind(char::is_whitespace) {
+            Some(end) if end > 0 => head[..end].trim_end(),
+            _ => head,
+        };
+        Some(Self { text, shortened: true })
+    }
+}
+
+impl Serialize for Excerpt<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.shortened {
+            serializer.collect_str(&format_args!("{}…", self.text))
+        } else {
+            serializer.serialize_str(self.text)
+        }
+    }
+}
+
+/// The fields a response carries: every stored field without `fields=`, otherwise the
+/// ones named plus `id`, in `Project` order
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSelection {
+    fields: Vec<ProjectField>,
+}
+
+impl Default for FieldSelection {
+    fn default() -> Self {
+        Self { fields: ProjectField::ALL.to_vec() }
+    }
+}
+
+impl FieldSelection {
+    /// `fields=title,category,total_awarded`; `summary` stands for the `ProjectSummary`
+    /// fields and can be combined with others. Unknown names are rejected.
+    pub fn parse(value: Option<&str>) -> Result<Self, AppError> {
+        let Some(value) = value.filter(|v| !v.trim().is_empty()) else {
+            return Ok(Self::default());
+        };
+
+        let mut requested = vec![ProjectField::Id];
+        for name in value.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            match name {
+                "summary" => requested.extend(ProjectField::SUMMARY),
+                name => requested.push(ProjectField::parse(name).ok_or_else(|| {
+                    AppError::BadRequest(format!(
+                        "Unknown field `{}`; expected summary, excerpt or a project field such as title, category or total_awarded",
+                        name
+                    ))
+                })?),
+            }
+        }
+
+        let fields = ProjectField::ALL
+            .into_iter()
+            .chain([ProjectField::Excerpt])
+            .filter(|field| requested.contains(field))
+            .collect();
+        Ok(Self { fields })
+    }
+}
+
+/// A dataset project as a response shows it: its selected fields, borrowed rather than
+/// cloned, and whatever the request attached
+#[derive(Debug, Clone)]
+pub struct ProjectView<'a> {
+    pub project: &'a Project,
+    fields: &'a [ProjectField],
+    pub enrichment: Option<ProjectEnrichment>,
+    pub search_match: Option<SearchMatch>,
+}
+
+impl<'a> ProjectView<'a> {
+    /// Every stored field and nothing attached; serializes exactly like `project`
+    pub fn new(project: &'a Project) -> Self {
+        Self { project, fields: &ProjectField::ALL, enrichment: None, search_match: None }
+    }
+
+    pub fn select(&mut self, fields: &'a FieldSelection) {
+        self.fields = &fields.fields;
+    }
+}
+
+impl Serialize for ProjectView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for field in self.fields {
+            field.serialize_entry(self.project, &mut map)?;
+        }
+        if let Some(enrichment) = &self.enrichment {
+            map.serialize_entry("enrichment", enrichment)?;
+        }
+        if let Some(search_match) = &self.search_match {
+            map.serialize_entry("search_match", search_match)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+    use shared::ProjectSummary;
+
+    fn project(description: &str) -> Project {
+        test_support::project(json!({
+            "title": "Stellar Pay",
+            "type": "Application",
+            "category": "Applications",
+            "description": description,
+            "total_awarded": 50000.0,
+            "soroban": true,
+            "tags": ["payments"],
+            "funding_details": "SCF #12",
+        }))
+    }
+
+    #[test]
+    fn test_views_serialize_like_projects_and_summaries() {
+        let long = "word ".repeat(60);
+        let project = project(&long);
+
+        let full = serde_json::to_value(ProjectView::new(&project)).unwrap();
+        assert_eq!(full, serde_json::to_value(&project).unwrap());
+
+        let summary_fields = FieldSelection::parse(Some("summary")).unwrap();
+        let mut view = ProjectView::new(&project);
+        view.select(&summary_fields);
+        let summary: ProjectSummary = serde_json::from_value(serde_json::to_value(&view).unwrap()).unwrap();
+        assert_eq!(summary.id, "stellar-pay");
+        assert_eq!(summary.project_type.as_deref(), Some("Application"));
+        let excerpt = summary.excerpt.unwrap();
+        assert!(excerpt.ends_with("word…"));
+        assert!(excerpt.chars().count() <= EXCERPT_CHARS + 1);
+
+        let short = self::project("  Cross-border payments.  ");
+        let excerpt = short.description.as_deref().and_then(Excerpt::of).unwrap();
+        assert_eq!(serde_json::to_value(&excerpt).unwrap(), "Cross-border payments.");
+    }
+
+    #[test]
+    fn test_all_covers_every_project_field() {
+        // Exhaustive on purpose: a new `Project` field stops this compiling until it is given a
+        // value here, and then fails until `ProjectField::ALL` serializes it
+        let text = |value: &str| Some(value.to_string());
+        let project = Project {
+            id: "stellar-pay".to_string(),
+            title: "Stellar Pay".to_string(),
+            project_type: text("Application"),
+            company: text("Stellar Pay Ltd"),
+            country: text("Kenya"),
+            description: text("Payments"),
+            category: text("Applications"),
+            total_awarded: Some(50000.0),
+            programs: text("Build"),
+            rounds: text("SCF #12"),
+            quarters: text("Q2 '23"),
+            integration_status: text("Mainnet"),
+            open_source: text("Fully Open-Source"),
+            website: text("https://pay.example"),
+            github: text("https://github.com/pay"),
+            soroban: Some(true),
+            other_chains: text("Ethereum"),
+            regions: text("Africa"),
+            traction: text("10k users"),
+            tags: vec!["payments".to_string()],
+            funding_details: text("SCF #12"),
+            status: text("Active"),
+            announcement_link: text("https://pay.example/news"),
+            parsed: Default::default(),
+        };
+
+        let stored = serde_json::to_value(&project).unwrap();
+        assert_eq!(serde_json::to_value(ProjectView::new(&project)).unwrap(), stored);
+        assert_eq!(stored.as_object().unwrap().len(), ProjectField::ALL.len());
+    }
+
+    #[test]
+    fn test_parse_field_selection() {
+        let selection = FieldSelection::parse(Some("total_awarded, title,category,title")).unwrap();
+        assert_eq!(
+            selection.fields,
+            [ProjectField::Id, ProjectField::Title, ProjectField::Category, ProjectField::TotalAwarded]
+        );
+
+        let project = project("Payments");
+        let mut view = ProjectView::new(&project);
+        view.select(&selection);
+        let json = serde_json::to_value(&view).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 4);
+        assert!(json.get("description").is_none());
+
+        assert_eq!(FieldSelection::parse(Some(" ")).unwrap(), FieldSelection::default());
+        assert!(FieldSelection::parse(Some("title,popularity")).is_err());
+    }
+}
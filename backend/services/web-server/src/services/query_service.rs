@@ -0,0 +1,544 @@
+use shared::{Chain, Program, Project, QueryError, Quarter};
+use std::collections::{HashMap, HashSet};
+
+use crate::{services::ProjectService, Dataset};
+
+/// Field names accepted before an operator, listed in errors
+const FIELDS: &[&str] = &[
+    "category", "type", "country", "region", "program", "chain", "tag", "company", "status", "quarter",
+    "year", "funding", "rounds", "soroban", "mainnet", "github", "open_source", "multichain", "website",
+    "traction",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl Comparison {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Eq => ":",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+        }
+    }
+
+    fn holds<T: PartialOrd>(&self, value: T, target: T) -> bool {
+        match self {
+            Self::Eq => value == target,
+            Self::Gt => value > target,
+            Self::Ge => value >= target,
+            Self::Lt => value < target,
+            Self::Le => value <= target,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flag {
+    Soroban,
+    Mainnet,
+    Github,
+    OpenSource,
+    Multichain,
+    Website,
+    Traction,
+}
+
+/// One condition on a project
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    Category(String),
+    Type(String),
+    Country(String),
+    Region(String),
+    Program(Program),
+    Chain(Chain),
+    Tag(String),
+    Company(String),
+    Status(String),
+    Quarter(Comparison, Quarter),
+    Year(Comparison, u16),
+    Funding(Comparison, f64),
+    Rounds(Comparison, usize),
+    Flag(Flag, bool),
+    /// A bare word or quoted phrase, matched through the search index
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Term(Term),
+}
+
+/// Character range of a token in the query
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Span {
+    start: usize,
+    len: usize,
+}
+
+impl Span {
+    fn error(&self, message: impl Into<String>) -> QueryError {
+        QueryError::new(message, self.start, self.len)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    /// `field:value`, `field>=value`, or a bare word or phrase when `field` is `None`
+    Term {
+        field: Option<(String, Span)>,
+        op: Option<(Comparison, Span)>,
+        value: (String, Span),
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    span: Span,
+}
+
+fn is_operator(c: char) -> bool {
+    matches!(c, ':' | '=' | '<' | '>')
+}
+
+fn is_boundary(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '"')
+}
+
+/// The phrase opening at `start`, and the position after its closing quote
+fn quoted(chars: &[char], start: usize) -> Result<((String, Span), usize), QueryError> {
+    let close = chars[start + 1..]
+        .iter()
+        .position(|&c| c == '"')
+        .map(|offset| start + 1 + offset)
+        .ok_or_else(|| QueryError::new("unterminated quote", start, chars.len() - start))?;
+
+    let value = chars[start + 1..close].iter().collect();
+    Ok(((value, Span { start, len: close + 1 - start }), close + 1))
+}
+
+fn tokenize(chars: &[char]) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+        let kind = match chars[i] {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => {
+                i += 1;
+                TokenKind::LParen
+            }
+            ')' => {
+                i += 1;
+                TokenKind::RParen
+            }
+            '-' => {
+                i += 1;
+                TokenKind::Not
+            }
+            '"' => {
+                let (value, end) = quoted(chars, i)?;
+                i = end;
+                TokenKind::Term { field: None, op: None, value }
+            }
+            _ => {
+                while i < chars.len() && !is_boundary(chars[i]) && !is_operator(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+
+                if i < chars.len() && is_operator(chars[i]) {
+                    let op_len = if matches!(chars[i], '<' | '>') && chars.get(i + 1) == Some(&'=') { 2 } else { 1 };
+                    let op = match (chars[i], op_len) {
+                        ('>', 2) => Comparison::Ge,
+                        ('<', 2) => Comparison::Le,
+                        ('>', _) => Comparison::Gt,
+                        ('<', _) => Comparison::Lt,
+                        _ => Comparison::Eq,
+                    };
+                    let op_span = Span { start: i, len: op_len };
+                    if word.is_empty() {
+                        return Err(op_span.error(format!("expected a field name before `{}`", op.symbol())));
+                    }
+                    i += op_len;
+
+                    let value = if chars.get(i) == Some(&'"') {
+                        let (value, end) = quoted(chars, i)?;
+                        i = end;
+                        value
+                    } else {
+                        let value_start = i;
+                        while i < chars.len() && !is_boundary(chars[i]) {
+                            i += 1;
+                        }
+                        let value: String = chars[value_start..i].iter().collect();
+                        (value, Span { start: value_start, len: i - value_start })
+                    };
+                    if value.0.trim().is_empty() {
+                        return Err(QueryError::new(
+                            format!("expected a value after `{}{}`", word, op.symbol()),
+                            start,
+                            i - start,
+                        ));
+                    }
+
+                    let field_span = Span { start, len: word.chars().count() };
+                    TokenKind::Term { field: Some((word, field_span)), op: Some((op, op_span)), value }
+                } else {
+                    match word.as_str() {
+                        "AND" => TokenKind::And,
+                        "OR" => TokenKind::Or,
+                        "NOT" => TokenKind::Not,
+                        _ => TokenKind::Term { field: None, op: None, value: (word, Span { start, len: i - start }) },
+                    }
+                }
+            }
+        };
+        tokens.push(Token { kind, span: Span { start, len: i - start } });
+    }
+
+    Ok(tokens)
+}
+
+/// "100k", "1.5m", "$250,000"
+fn parse_amount(value: &str) -> Option<f64> {
+    let value = value.trim().trim_start_matches('$').replace([',', '_'], "").to_lowercase();
+    let (number, multiplier) = match value.strip_suffix('k') {
+        Some(number) => (number.to_string(), 1_000.0),
+        None => match value.strip_suffix('m') {
+            Some(number) => (number.to_string(), 1_000_000.0),
+            None => (value, 1.0),
+        },
+    };
+    number.parse::<f64>().ok().filter(|n| n.is_finite()).map(|n| n * multiplier)
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "yes" | "1" => Some(true),
+        "false" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn resolve_term(
+    field: Option<(String, Span)>,
+    op: Option<(Comparison, Span)>,
+    (value, value_span): (String, Span),
+) -> Result<Term, QueryError> {
+    let (Some((field, field_span)), Some((op, op_span))) = (field, op) else {
+        return Ok(Term::Text(value));
+    };
+    let name = field.to_lowercase().replace('-', "_");
+    let flag = match name.as_str() {
+        "soroban" => Some(Flag::Soroban),
+        "mainnet" => Some(Flag::Mainnet),
+        "github" => Some(Flag::Github),
+        "open_source" | "opensource" => Some(Flag::OpenSource),
+        "multichain" => Some(Flag::Multichain),
+        "website" => Some(Flag::Website),
+        "traction" => Some(Flag::Traction),
+        _ => None,
+    };
+    let comparable = matches!(name.as_str(), "quarter" | "year" | "funding" | "rounds");
+    if !FIELDS.contains(&name.as_str()) && name != "opensource" {
+        return Err(field_span.error(format!("unknown field `{}`, expected one of {}", field, FIELDS.join(", "))));
+    }
+    if op != Comparison::Eq && !comparable {
+        return Err(op_span.error(format!("`{}` does not support `{}`", field, op.symbol())));
+    }
+
+    let invalid = |expected: &str| value_span.error(format!("`{}` is not {}", value, expected));
+    let term = match name.as_str() {
+        "category" => Term::Category(value),
+        "type" => Term::Type(value),
+        "country" => Term::Country(value),
+        "region" => Term::Region(value),
+        "program" => Term::Program(Program::parse(&value)),
+        "chain" => Term::Chain(Chain::parse(&value)),
+        "tag" => Term::Tag(value),
+        "company" => Term::Company(value),
+        "status" => Term::Status(value),
+        "quarter" => Term::Quarter(op, Quarter::parse(&value).ok_or_else(|| invalid("a quarter such as Q2 2023"))?),
+        "year" => Term::Year(op, value.trim().parse().map_err(|_| invalid("a year"))?),
+        "funding" => Term::Funding(op, parse_amount(&value).ok_or_else(|| invalid("an amount such as 100k"))?),
+        "rounds" => Term::Rounds(op, value.trim().parse().map_err(|_| invalid("a number of rounds"))?),
+        _ => Term::Flag(
+            flag.expect("every remaining field is a flag"),
+            parse_bool(&value).ok_or_else(|| invalid("true or false"))?,
+        ),
+    };
+    Ok(term)
+}
+
+/// Recursive descent over the tokens: OR binds loosest, then AND (explicit or implied
+/// between neighbouring terms), then NOT and `-`
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    query_len: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.position).map(|token| &token.kind)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, QueryError> {
+        let mut exprs = vec![self.parse_and()?];
+        while self.peek() == Some(&TokenKind::Or) {
+            self.position += 1;
+            exprs.push(self.parse_and()?);
+        }
+        Ok(if exprs.len() == 1 { exprs.remove(0) } else { Expr::Or(exprs) })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, QueryError> {
+        let mut exprs = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                None | Some(TokenKind::Or) | Some(TokenKind::RParen) => break,
+                Some(TokenKind::And) => self.position += 1,
+                _ => {}
+            }
+            exprs.push(self.parse_unary()?);
+        }
+        Ok(if exprs.len() == 1 { exprs.remove(0) } else { Expr::And(exprs) })
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, QueryError> {
+        if self.peek() == Some(&TokenKind::Not) {
+            self.position += 1;
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, QueryError> {
+        let Some(token) = self.next() else {
+            return Err(QueryError::new("query ends where a term was expected", self.query_len, 1));
+        };
+
+        match token.kind {
+            TokenKind::LParen => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Some(Token { kind: TokenKind::RParen, .. }) => Ok(expr),
+                    _ => Err(token.span.error("unclosed `(`")),
+                }
+            }
+            TokenKind::Term { field, op, value } => Ok(Expr::Term(resolve_term(field, op, value)?)),
+            TokenKind::RParen => Err(token.span.error("expected a term, found `)`")),
+            TokenKind::And => Err(token.span.error("expected a term, found `AND`")),
+            TokenKind::Or => Err(token.span.error("expected a term, found `OR`")),
+            TokenKind::Not => unreachable!("handled by parse_unary"),
+        }
+    }
+}
+
+/// A parsed query-language string such as
+/// `category:defi funding>=100k soroban:true country:"Nigeria" program:build -chain:ethereum`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectQuery {
+    expr: Expr,
+}
+
+impl ProjectQuery {
+    /// Parse a query; a blank query matches every project
+    pub fn parse(query: &str) -> Result<Self, QueryError> {
+        let chars: Vec<char> = query.chars().collect();
+        let mut parser = Parser {
+            tokens: tokenize(&chars)?,
+            position: 0,
+            query_len: chars.len(),
+        };
+        if parser.tokens.is_empty() {
+            return Ok(Self { expr: Expr::And(Vec::new()) });
+        }
+
+        let expr = parser.parse_or()?;
+        if let Some(token) = parser.tokens.get(parser.position) {
+            return Err(token.span.error("unmatched `)`"));
+        }
+        Ok(Self { expr })
+    }
+
+    /// Resolve the free-text terms against the dataset's search index
+    pub fn matcher<'a>(&'a self, dataset: &'a Dataset) -> QueryMatcher<'a> {
+        fn texts<'e>(expr: &'e Expr, found: &mut Vec<&'e str>) {
+            match expr {
+                Expr::And(exprs) | Expr::Or(exprs) => exprs.iter().for_each(|e| texts(e, found)),
+                Expr::Not(expr) => texts(expr, found),
+                Expr::Term(Term::Text(text)) => found.push(text),
+                Expr::Term(_) => {}
+            }
+        }
+
+        let mut found = Vec::new();
+        texts(&self.expr, &mut found);
+        let text_matches = found
+            .into_iter()
+            .map(|text| {
+                let ids = dataset.search.search(text).map(|hits| {
+                    hits.iter()
+                        .map(|hit| dataset.projects[hit.position].id.as_str())
+                        .collect()
+                });
+                (text, ids)
+            })
+            .collect();
+
+        QueryMatcher { expr: &self.expr, text_matches }
+    }
+}
+
+/// A query bound to one dataset
+pub struct QueryMatcher<'a> {
+    expr: &'a Expr,
+    /// Ids matching each free-text term; `None` for terms without searchable words
+    text_matches: HashMap<&'a str, Option<HashSet<&'a str>>>,
+}
+
+impl QueryMatcher<'_> {
+    pub fn matches(&self, project: &Project) -> bool {
+        self.eval(self.expr, project)
+    }
+
+    fn eval(&self, expr: &Expr, project: &Project) -> bool {
+        match expr {
+            Expr::And(exprs) => exprs.iter().all(|e| self.eval(e, project)),
+            Expr::Or(exprs) => exprs.iter().any(|e| self.eval(e, project)),
+            Expr::Not(expr) => !self.eval(expr, project),
+            Expr::Term(term) => self.eval_term(term, project),
+        }
+    }
+
+    fn eval_term(&self, term: &Term, project: &Project) -> bool {
+        let contains = |field: &Option<String>, value: &str| {
+            field
+                .as_ref()
+                .is_some_and(|f| f.to_lowercase().contains(&value.trim().to_lowercase()))
+        };
+
+        match term {
+            Term::Category(category) => ProjectService::matches_category(project, category),
+            Term::Type(project_type) => ProjectService::matches_project_type(project, project_type),
+            Term::Country(country) => ProjectService::matches_country(project, country),
+            Term::Region(region) => ProjectService::matches_region(project, region),
+            Term::Program(program) => project.parsed.programs.contains(program),
+            Term::Chain(chain) => project
+                .parsed
+                .chains
+                .iter()
+                .any(|c| c.as_str().eq_ignore_ascii_case(chain.as_str())),
+            Term::Tag(tag) => project.tags.iter().any(|t| t.trim().eq_ignore_ascii_case(tag.trim())),
+            Term::Company(company) => contains(&project.company, company),
+            Term::Status(status) => contains(&project.status, status),
+            Term::Quarter(op, quarter) => project.parsed.quarters.iter().any(|q| op.holds(q, quarter)),
+            Term::Year(op, year) => project.parsed.quarters.iter().any(|q| op.holds(q.year(), *year)),
+            Term::Funding(op, amount) => op.holds(project.get_funding_amount_numeric().unwrap_or(0.0), *amount),
+            Term::Rounds(op, rounds) => op.holds(project.parsed.rounds.len(), *rounds),
+            Term::Flag(flag, expected) => {
+                let actual = match flag {
+                    Flag::Soroban => project.uses_soroban(),
+                    Flag::Mainnet => project.is_mainnet(),
+                    Flag::Github => project.github.is_some(),
+                    Flag::OpenSource => project.is_open_source(),
+                    Flag::Multichain => project.is_multichain(),
+                    Flag::Website => project.website.is_some(),
+                    Flag::Traction => project.traction.is_some(),
+                };
+                actual == *expected
+            }
+            Term::Text(text) => match self.text_matches.get(text.as_str()) {
+                Some(Some(ids)) => ids.contains(project.id.as_str()),
+                _ => true,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(term: Term) -> Expr {
+        Expr::Term(term)
+    }
+
+    #[test]
+    fn test_parse_builds_boolean_tree() {
+        let query = ProjectQuery::parse(
+            r#"category:defi funding>=100k soroban:true country:"Nigeria" program:build -chain:ethereum"#,
+        )
+        .unwrap();
+        assert_eq!(
+            query.expr,
+            Expr::And(vec![
+                term(Term::Category("defi".into())),
+                term(Term::Funding(Comparison::Ge, 100_000.0)),
+                term(Term::Flag(Flag::Soroban, true)),
+                term(Term::Country("Nigeria".into())),
+                term(Term::Program(Program::Build)),
+                Expr::Not(Box::new(term(Term::Chain(Chain::Ethereum)))),
+            ])
+        );
+
+        let query = ProjectQuery::parse("(country:KE OR country:NG) AND NOT mainnet:yes wallet").unwrap();
+        assert_eq!(
+            query.expr,
+            Expr::And(vec![
+                Expr::Or(vec![term(Term::Country("KE".into())), term(Term::Country("NG".into()))]),
+                Expr::Not(Box::new(term(Term::Flag(Flag::Mainnet, true)))),
+                term(Term::Text("wallet".into())),
+            ])
+        );
+
+        assert_eq!(ProjectQuery::parse("  ").unwrap().expr, Expr::And(Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_problem() {
+        let error = |query: &str| {
+            let error = ProjectQuery::parse(query).unwrap_err();
+            (error.position, error.length)
+        };
+
+        assert_eq!(error("soroban:true colour:red"), (13, 6));
+        assert_eq!(error("funding>=lots"), (9, 4));
+        assert_eq!(error("category>defi"), (8, 1));
+        assert_eq!(error(r#"country:"Nigeria"#), (8, 8));
+        assert_eq!(error("(country:KE OR country:NG"), (0, 1));
+        assert_eq!(error("country:KE)"), (10, 1));
+        assert_eq!(error("country:KE OR"), (13, 1));
+        assert_eq!(error("quarter:"), (0, 8));
+    }
+}
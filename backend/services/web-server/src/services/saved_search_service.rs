@@ -0,0 +1,168 @@
+use sqlx::PgPool;
+use lib_web::AppError;
+use shared::{
+    CreateSavedSearchRequest, ProjectFilter, RunSavedSearchQuery, SavedSearch, SavedSearchDelta,
+    SavedSearchRunResponse,
+};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::{
+    extractors::parse_list_query, repositories::SavedSearchRepository,
+    services::{ProjectService, ProjectView},
+    Dataset,
+};
+
+const MAX_NAME_LEN: usize = 200;
+
+fn validate_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+        return Err(AppError::BadRequest(format!(
+            "name must be between 1 and {} characters",
+            MAX_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+/// Added and removed ids between two runs; `previous` is `None` before the first run
+fn delta(previous: Option<(usize, &[String])>, current: &[String]) -> SavedSearchDelta {
+    let before: HashSet<&String> = previous.map(|(_, ids)| ids.iter().collect()).unwrap_or_default();
+    let after: HashSet<&String> = current.iter().collect();
+
+    SavedSearchDelta {
+        previous_count: previous.map(|(count, _)| count),
+        count_change: current.len() as i64 - previous.map(|(count, _)| count as i64).unwrap_or(0),
+        added: current.iter().filter(|id| !before.contains(id)).cloned().collect(),
+        removed: previous
+            .map(|(_, ids)| ids.iter().filter(|id| !after.contains(id)).cloned().collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// Whether a run becomes the baseline for the next one: the first page, on the current dataset
+fn records_run(paging: &RunSavedSearchQuery, current: bool) -> bool {
+    current && paging.cursor.is_none() && paging.page.unwrap_or(1) == 1
+}
+
+pub struct SavedSearchService;
+
+impl SavedSearchService {
+    pub async fn list(pool: &PgPool, user_id: Uuid) -> Result<Vec<SavedSearch>, AppError> {
+        SavedSearchRepository::list(pool, user_id).await
+    }
+
+    /// Save a filter, or a `/api/projects` query string, under a name unique per user.
+    /// The filter is checked like a listing request but stored without pagination.
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        req: CreateSavedSearchRequest,
+    ) -> Result<SavedSearch, AppError> {
+        let name = validate_name(&req.name)?;
+        let mut filter: ProjectFilter = match (req.filter, req.query.as_deref()) {
+            (Some(filter), None) => filter,
+            (None, Some(query)) => parse_list_query(query)?,
+            _ => {
+                return Err(AppError::BadRequest(
+                    "Provide either filter or query".to_string(),
+                ))
+            }
+        };
+        (filter.page, filter.per_page, filter.cursor) = (None, None, None);
+
+        ProjectService::validate_filter(&filter)?;
+        ProjectService::parse_query(&filter)?;
+
+        SavedSearchRepository::create(pool, user_id, &name, &filter).await
+    }
+
+    pub async fn rename(
+        pool: &PgPool,
+        user_id: Uuid,
+        id: Uuid,
+        name: &str,
+    ) -> Result<SavedSearch, AppError> {
+        let name = validate_name(name)?;
+        SavedSearchRepository::rename(pool, user_id, id, &name).await
+    }
+
+    pub async fn delete(pool: &PgPool, user_id: Uuid, id: Uuid) -> Result<(), AppError> {
+        SavedSearchRepository::delete(pool, user_id, id).await
+    }
+
+    /// Run a saved search against the dataset, returning one page of results and what
+    /// changed since the previous run. Only a first page on the current dataset records the
+    /// run, so paging through results or an older snapshot keeps the baseline.
+    pub async fn run<'a>(
+        pool: &PgPool,
+        dataset: &'a Dataset,
+        current: bool,
+        user_id: Uuid,
+        id: Uuid,
+        paging: RunSavedSearchQuery,
+    ) -> Result<SavedSearchRunResponse<ProjectView<'a>>, AppError> {
+        let records = records_run(&paging, current);
+        let (search, previous_ids) = SavedSearchRepository::find(pool, user_id, id).await?;
+
+        let filter = ProjectFilter {
+            page: paging.page,
+            per_page: paging.per_page,
+            cursor: paging.cursor,
+            ..search.filter.clone()
+        };
+        let (results, mut ids) = ProjectService::filter_projects_with_ids(dataset, filter)?;
+        ids.sort();
+
+        let previous = search.result_count.map(|count| (count, previous_ids.as_slice()));
+        let delta = delta(previous, &ids);
+        let search = if records {
+            let result_delta = previous.map(|_| delta.count_change);
+            SavedSearchRepository::record_run(pool, user_id, id, result_delta, &ids).await?
+        } else {
+            search
+        };
+
+        Ok(SavedSearchRunResponse { search, results, delta })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_delta_between_runs() {
+        let first = delta(None, &ids(&["a", "b"]));
+        assert_eq!(first.previous_count, None);
+        assert_eq!(first.count_change, 2);
+        assert_eq!(first.added, ids(&["a", "b"]));
+        assert!(first.removed.is_empty());
+
+        let previous = ids(&["a", "b"]);
+        let second = delta(Some((2, &previous)), &ids(&["b", "c", "d"]));
+        assert_eq!(second.previous_count, Some(2));
+        assert_eq!(second.count_change, 1);
+        assert_eq!(second.added, ids(&["c", "d"]));
+        assert_eq!(second.removed, ids(&["a"]));
+    }
+
+    #[test]
+    fn test_only_first_page_on_current_dataset_records_run() {
+        let paging = |page: Option<usize>, cursor: Option<&str>| RunSavedSearchQuery {
+            page,
+            per_page: Some(20),
+            cursor: cursor.map(str::to_string),
+        };
+        assert!(records_run(&paging(None, None), true));
+        assert!(records_run(&paging(Some(1), None), true));
+        assert!(!records_run(&paging(Some(2), None), true));
+        assert!(!records_run(&paging(None, Some("abc")), true));
+        assert!(!records_run(&paging(None, None), false));
+    }
+}
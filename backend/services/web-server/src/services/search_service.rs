@@ -0,0 +1,448 @@
+use shared::{Project, SearchMatch, WebsiteMetadata};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Bound;
+
+use crate::services::Enrichment;
+
+/// BM25 term-frequency saturation
+const K1: f64 = 1.2;
+/// BM25 length normalization
+const B: f64 = 0.75;
+/// Words kept on each side of the first match in a snippet
+const SNIPPET_CONTEXT_WORDS: usize = 12;
+/// Shortest final query token that also matches as a prefix, for search-as-you-type
+const MIN_PREFIX_LEN: usize = 3;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "into", "is", "it", "its",
+    "of", "on", "or", "that", "the", "this", "to", "with",
+];
+
+/// Indexed fields and their BM25F weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Title,
+    Company,
+    Tags,
+    Description,
+    Website,
+}
+
+impl Field {
+    const ALL: [Field; 5] = [Self::Title, Self::Company, Self::Tags, Self::Description, Self::Website];
+
+    fn weight(&self) -> f64 {
+        match self {
+            Self::Title => 3.0,
+            Self::Company => 2.0,
+            Self::Tags => 2.0,
+            Self::Description => 1.0,
+            Self::Website => 0.5,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Company => "company",
+            Self::Tags => "tags",
+            Self::Description => "description",
+            Self::Website => "website",
+        }
+    }
+
+    fn text(&self, project: &Project, website: Option<&WebsiteMetadata>) -> String {
+        match self {
+            Self::Title => project.title.clone(),
+            Self::Company => project.company.clone().unwrap_or_default(),
+            Self::Tags => project.tags.join(", "),
+            Self::Description => project.description.clone().unwrap_or_default(),
+            Self::Website => website
+                .map(|w| {
+                    let technologies = &w.detected_technologies;
+                    [w.page_title.as_deref(), w.meta_description.as_deref()]
+                        .into_iter()
+                        .flatten()
+                        .map(str::to_string)
+                        .chain(technologies.stellar.iter().cloned())
+                        .chain(technologies.blockchain.iter().cloned())
+                        .chain(technologies.frameworks.iter().cloned())
+                        .collect::<Vec<_>>()
+                        .join(". ")
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+const FIELD_COUNT: usize = Field::ALL.len();
+
+/// A term's occurrences in one project, per field
+#[derive(Debug)]
+struct Posting {
+    doc: usize,
+    term_frequency: [u32; FIELD_COUNT],
+}
+
+/// Running score of one project while a query is evaluated
+#[derive(Default)]
+struct Accumulator {
+    score: f64,
+    by_field: [f64; FIELD_COUNT],
+    terms: HashSet<String>,
+    /// Query slots matched so far; a project must match them in order to stay a candidate
+    matched_slots: usize,
+}
+
+/// One project matching a query
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    /// Position in `Dataset::projects`
+    pub position: usize,
+    pub score: f64,
+    /// Index terms the project matched
+    terms: HashSet<String>,
+    /// Field contributing most to the score
+    best_field: Field,
+}
+
+/// Inverted index over project text, scored with BM25F; built once per dataset load
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    postings: HashMap<String, Vec<Posting>>,
+    /// Every indexed word, lowercased, with its stem; sorted for prefix lookups
+    vocabulary: BTreeMap<String, String>,
+    field_lengths: Vec<[u32; FIELD_COUNT]>,
+    average_field_lengths: [f64; FIELD_COUNT],
+}
+
+impl SearchIndex {
+    pub fn build(projects: &[Project], enrichment: &Enrichment) -> Self {
+        let mut postings: HashMap<String, Vec<Posting>> = HashMap::new();
+        let mut vocabulary = BTreeMap::new();
+        let mut field_lengths = Vec::with_capacity(projects.len());
+
+        for (doc, project) in projects.iter().enumerate() {
+            let website = enrichment.website.get(&project.id);
+            let mut lengths = [0u32; FIELD_COUNT];
+            let mut frequencies: HashMap<String, [u32; FIELD_COUNT]> = HashMap::new();
+
+            for (f, field) in Field::ALL.iter().enumerate() {
+                let text = field.text(project, website);
+                for (term, (start, end)) in analyze(&text) {
+                    vocabulary.entry(text[start..end].to_lowercase()).or_insert_with(|| term.clone());
+                    lengths[f] += 1;
+                    frequencies.entry(term).or_default()[f] += 1;
+                }
+            }
+
+            for (term, term_frequency) in frequencies {
+                postings.entry(term).or_default().push(Posting { doc, term_frequency });
+            }
+            field_lengths.push(lengths);
+        }
+
+        let mut average_field_lengths = [0.0; FIELD_COUNT];
+        if !field_lengths.is_empty() {
+            for (f, average) in average_field_lengths.iter_mut().enumerate() {
+                let total: u32 = field_lengths.iter().map(|lengths| lengths[f]).sum();
+                *average = total as f64 / field_lengths.len() as f64;
+            }
+        }
+
+        Self {
+            postings,
+            vocabulary,
+            field_lengths,
+            average_field_lengths,
+        }
+    }
+
+    /// Projects containing every query term, best first. `None` when the query has no
+    /// searchable terms (only stopwords or punctuation), in which case nothing is filtered.
+    pub fn search(&self, query: &str) -> Option<Vec<SearchHit>> {
+        let analyzed = analyze(query);
+        let (_, (start, end)) = analyzed.last()?;
+        // Each query token is a slot of index terms; the last one also matches words it prefixes
+        let last_word = query[*start..*end].to_lowercase();
+        let tokens: Vec<String> = analyzed.into_iter().map(|(term, _)| term).collect();
+
+        let slots: Vec<HashSet<&str>> = tokens
+            .iter()
+            .enumerate()
+            .map(|(i, token)| {
+                let mut terms: HashSet<&str> = self.postings.get_key_value(token).map(|(t, _)| t.as_str()).into_iter().collect();
+                if i == tokens.len() - 1 && last_word.chars().count() >= MIN_PREFIX_LEN {
+                    terms.extend(self.completions(&last_word));
+                }
+                terms
+            })
+            .collect();
+
+        let doc_count = self.field_lengths.len() as f64;
+        let mut candidates: HashMap<usize, Accumulator> = HashMap::new();
+
+        for (slot_index, slot) in slots.iter().enumerate() {
+            let mut matched_docs = HashSet::new();
+            for term in slot {
+                let postings = &self.postings[*term];
+                let document_frequency = postings.len() as f64;
+                let idf = (1.0 + (doc_count - document_frequency + 0.5) / (document_frequency + 0.5)).ln();
+
+                for posting in postings {
+                    let candidate = candidates.entry(posting.doc).or_default();
+                    if candidate.matched_slots < slot_index {
+                        continue;
+                    }
+                    for (f, field) in Field::ALL.iter().enumerate() {
+                        let contribution = field.weight() * idf * self.saturate(posting, f);
+                        candidate.score += contribution;
+                        candidate.by_field[f] += contribution;
+                    }
+                    candidate.terms.insert(term.to_string());
+                    matched_docs.insert(posting.doc);
+                }
+            }
+            for doc in matched_docs {
+                if let Some(candidate) = candidates.get_mut(&doc) {
+                    candidate.matched_slots = slot_index + 1;
+                }
+            }
+        }
+
+        let mut results: Vec<SearchHit> = candidates
+            .into_iter()
+            .filter(|(_, candidate)| candidate.matched_slots == slots.len())
+            .map(|(position, candidate)| {
+                let best = (0..FIELD_COUNT)
+                    .max_by(|a, b| candidate.by_field[*a].total_cmp(&candidate.by_field[*b]))
+                    .unwrap_or(0);
+                SearchHit {
+                    position,
+                    score: candidate.score,
+                    terms: candidate.terms,
+                    best_field: Field::ALL[best],
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.position.cmp(&b.position)));
+
+        Some(results)
+    }
+
+    /// Index terms of the words starting with `prefix`
+    fn completions<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.vocabulary
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(move |(word, _)| word.starts_with(prefix))
+            .map(|(_, term)| term.as_str())
+    }
+
+    /// Score, field and highlighted snippet for a hit, as returned to clients
+    pub fn search_match(hit: &SearchHit, project: &Project, enrichment: &Enrichment) -> SearchMatch {
+        let text = hit.best_field.text(project, enrichment.website.get(&project.id));
+
+        SearchMatch {
+            score: (hit.score * 1000.0).round() / 1000.0,
+            field: hit.best_field.as_str().to_string(),
+            snippet: snippet(&text, &hit.terms),
+        }
+    }
+
+    /// BM25 term-frequency component for one field of a posting
+    fn saturate(&self, posting: &Posting, field: usize) -> f64 {
+        let term_frequency = posting.term_frequency[field] as f64;
+        if term_frequency == 0.0 {
+            return 0.0;
+        }
+        let average = self.average_field_lengths[field].max(1.0);
+        let length = self.field_lengths[posting.doc][field] as f64;
+
+        term_frequency * (K1 + 1.0) / (term_frequency + K1 * (1.0 - B + B * length / average))
+    }
+}
+
+/// Lowercased, stemmed tokens without stopwords, with their byte ranges in `text`
+pub(crate) fn analyze(text: &str) -> Vec<(String, (usize, usize))> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (i, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                let word = text[s..i].to_lowercase();
+                if word.chars().count() > 1 && !STOPWORDS.contains(&word.as_str()) {
+                    tokens.push((stem(&word), (s, i)));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+/// Light English suffix stripping in the spirit of Porter's stemmer: plurals, -ed/-ing/-er,
+/// a few derivational endings and a final e. Query and index go through the same steps,
+/// so "payments", "paying" and "pay" all become "pay".
+fn stem(word: &str) -> String {
+    if word.len() <= 3 || !word.is_ascii() {
+        return word.to_string();
+    }
+    let mut w = word.to_string();
+
+    if w.ends_with("sses") {
+        w.truncate(w.len() - 2);
+    } else if let Some(base) = w.strip_suffix("ies") {
+        w = format!("{}y", base);
+    } else if w.ends_with('s') && !["ss", "us", "is"].iter().any(|s| w.ends_with(s)) {
+        w.pop();
+    }
+
+    for (suffix, min_base) in [("ing", 3), ("ed", 3), ("er", 4)] {
+        if let Some(base) = w.strip_suffix(suffix) {
+            if base.len() >= min_base && base.chars().any(|c| "aeiouy".contains(c)) {
+                w = base.to_string();
+                let bytes = w.as_bytes();
+                let n = bytes.len();
+                if n >= 2 && bytes[n - 1] == bytes[n - 2] && !b"aeiouslz".contains(&bytes[n - 1]) {
+                    w.pop();
+                }
+                break;
+            }
+        }
+    }
+
+    for (suffix, replacement) in [
+        ("ational", "ate"),
+        ("ization", "ize"),
+        ("ation", "ate"),
+        ("fulness", "ful"),
+        ("ness", ""),
+        ("ment", ""),
+        ("ful", ""),
+        ("ly", ""),
+    ] {
+        if let Some(base) = w.strip_suffix(suffix) {
+            if base.len() >= 3 {
+                w = format!("{}{}", base, replacement);
+                break;
+            }
+        }
+    }
+
+    if w.len() > 3 && w.ends_with('e') {
+        w.pop();
+    }
+
+    w
+}
+
+/// HTML-escaped excerpt around the first match, with matched words wrapped in `<mark>`
+fn snippet(text: &str, terms: &HashSet<String>) -> String {
+    let tokens = analyze(text);
+    let words: Vec<(usize, usize)> = text
+        .split_whitespace()
+        .map(|word| {
+            let start = word.as_ptr() as usize - text.as_ptr() as usize;
+            (start, start + word.len())
+        })
+        .collect();
+
+    let matched: Vec<(usize, usize)> = tokens
+        .into_iter()
+        .filter(|(term, _)| terms.contains(term))
+        .map(|(_, range)| range)
+        .collect();
+
+    let first_word = matched
+        .first()
+        .and_then(|(start, _)| words.iter().position(|(s, e)| s <= start && start < e))
+        .unwrap_or(0);
+    let from = first_word.saturating_sub(SNIPPET_CONTEXT_WORDS);
+    let to = (first_word + SNIPPET_CONTEXT_WORDS + 1).min(words.len());
+    let (Some(&(window_start, _)), Some(&(_, window_end))) = (words.get(from), to.checked_sub(1).and_then(|i| words.get(i)))
+    else {
+        return String::new();
+    };
+
+    let mut out = String::new();
+    if from > 0 {
+        out.push_str("… ");
+    }
+    let mut cursor = window_start;
+    for &(start, end) in matched.iter().filter(|(s, e)| *s >= window_start && *e <= window_end) {
+        out.push_str(&escape_html(&text[cursor..start]));
+        out.push_str("<mark>");
+        out.push_str(&escape_html(&text[start..end]));
+        out.push_str("</mark>");
+        cursor = end;
+    }
+    out.push_str(&escape_html(&text[cursor..window_end]));
+    if to < words.len() {
+        out.push_str(" …");
+    }
+
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn project(title: &str, description: &str) -> Project {
+        test_support::project(json!({
+            "title": title,
+            "description": description,
+        }))
+    }
+
+    #[test]
+    fn test_stem_merges_inflections() {
+        assert_eq!(stem("payments"), "pay");
+        assert_eq!(stem("paying"), "pay");
+        assert_eq!(stem("lenders"), stem("lending"));
+        assert_eq!(stem("libraries"), stem("library"));
+        assert_eq!(stem("stablecoins"), "stablecoin");
+        assert_eq!(stem("status"), "status");
+    }
+
+    #[test]
+    fn test_search_ranks_and_highlights() {
+        let projects = vec![
+            project("Remit", "Cross-border payments for families, with fast payment settlement"),
+            project("Payments Hub", "A payment gateway for merchants"),
+            project("Lendy", "Lending protocol"),
+        ];
+        let index = SearchIndex::build(&projects, &Enrichment::default());
+
+        let hits = index.search("payment").unwrap();
+        let titles: Vec<&str> = hits.iter().map(|h| projects[h.position].title.as_str()).collect();
+        assert_eq!(titles, ["Payments Hub", "Remit"]);
+
+        // Every term must match
+        assert!(index.search("payment lending").unwrap().is_empty());
+        // The last token matches as a prefix while typing
+        assert_eq!(index.search("lend").unwrap().len(), 1);
+        assert!(index.search("the").is_none());
+
+        let remit = hits.iter().find(|h| h.position == 0).unwrap();
+        let found = SearchIndex::search_match(remit, &projects[0], &Enrichment::default());
+        assert_eq!(found.field, "description");
+        assert_eq!(
+            found.snippet,
+            "Cross-border <mark>payments</mark> for families, with fast <mark>payment</mark> settlement"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use shared::{Project, ProjectCategory, SimilarityBreakdown};
+use std::collections::{HashMap, HashSet};
+
+use crate::services::analyze;
+
+/// Similar projects returned when the request sets no limit
+const DEFAULT_LIMIT: usize = 5;
+const MAX_LIMIT: usize = 20;
+
+/// Share of each dimension in the blended score; they add up to 1
+const TEXT_WEIGHT: f64 = 0.5;
+const CATEGORY_WEIGHT: f64 = 0.2;
+const TYPE_WEIGHT: f64 = 0.1;
+const CHAINS_WEIGHT: f64 = 0.05;
+const SOROBAN_WEIGHT: f64 = 0.1;
+const COUNTRY_WEIGHT: f64 = 0.05;
+
+/// Unit-length TF-IDF vector as (term id, weight), sorted by term id
+type Vector = Vec<(usize, f64)>;
+
+fn cosine(a: &Vector, b: &Vector) -> f64 {
+    let (mut i, mut j, mut dot) = (0, 0, 0.0);
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                dot += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    dot
+}
+
+fn same(a: bool) -> f64 {
+    if a { 1.0 } else { 0.0 }
+}
+
+/// Known categories compare by taxonomy, anything else by the raw value
+fn category_similarity(a: &Project, b: &Project) -> f64 {
+    match (a.get_category(), b.get_category()) {
+        (ProjectCategory::Other, ProjectCategory::Other) => same(
+            a.category.as_deref().zip(b.category.as_deref())
+                .is_some_and(|(a, b)| a.trim().eq_ignore_ascii_case(b.trim())),
+        ),
+        (a, b) => same(a == b),
+    }
+}
+
+/// Structured similarity of two projects; `text` is left at 0 for the caller
+fn structured(a: &Project, b: &Project) -> SimilarityBreakdown {
+    let project_type = match (a.parsed.project_type, b.parsed.project_type) {
+        (Some(a), Some(b)) => same(a == b),
+        _ => same(
+            a.project_type.as_deref().zip(b.project_type.as_deref())
+                .is_some_and(|(a, b)| a.trim().eq_ignore_ascii_case(b.trim())),
+        ),
+    };
+
+    let chains_a: HashSet<_> = a.parsed.chains.iter().collect();
+    let chains_b: HashSet<_> = b.parsed.chains.iter().collect();
+    let union = chains_a.union(&chains_b).count();
+    // Two Stellar-only projects share no chain data, which says nothing about how alike they are
+    let chains = if union == 0 {
+        0.0
+    } else {
+        chains_a.intersection(&chains_b).count() as f64 / union as f64
+    };
+
+    SimilarityBreakdown {
+        text: 0.0,
+        category: category_similarity(a, b),
+        project_type,
+        chains,
+        soroban: same(a.uses_soroban() == b.uses_soroban()),
+        country: same(a.parsed.country.is_some() && a.parsed.country == b.parsed.country),
+    }
+}
+
+fn blended_score(breakdown: &SimilarityBreakdown) -> f64 {
+    TEXT_WEIGHT * breakdown.text
+        + CATEGORY_WEIGHT * breakdown.category
+        + TYPE_WEIGHT * breakdown.project_type
+        + CHAINS_WEIGHT * breakdown.chains
+        + SOROBAN_WEIGHT * breakdown.soroban
+        + COUNTRY_WEIGHT * breakdown.country
+}
+
+/// TF-IDF vectors over each project's description and tags; built once per dataset load
+#[derive(Debug, Default)]
+pub struct SimilarityIndex {
+    terms: HashMap<String, usize>,
+    /// Smoothed inverse document frequency per term id
+    idf: Vec<f64>,
+    /// One per project, in `Dataset::projects` order
+    vectors: Vec<Vector>,
+}
+
+impl SimilarityIndex {
+    pub fn build(projects: &[Project]) -> Self {
+        let mut terms: HashMap<String, usize> = HashMap::new();
+        let mut document_frequency: Vec<usize> = Vec::new();
+        let mut frequencies: Vec<HashMap<usize, usize>> = Vec::with_capacity(projects.len());
+
+        for project in projects {
+            let text = format!(
+                "{} {}",
+                project.description.as_deref().unwrap_or_default(),
+                project.tags.join(" ")
+            );
+            let mut counts: HashMap<usize, usize> = HashMap::new();
+            for (term, _) in analyze(&text) {
+                let next_id = terms.len();
+                let id = *terms.entry(term).or_insert(next_id);
+                if id == document_frequency.len() {
+                    document_frequency.push(0);
+                }
+                *counts.entry(id).or_insert(0) += 1;
+            }
+            for &id in counts.keys() {
+                document_frequency[id] += 1;
+            }
+            frequencies.push(counts);
+        }
+
+        let n = projects.len() as f64;
+        let idf: Vec<f64> = document_frequency
+            .iter()
+            .map(|&df| ((1.0 + n) / (1.0 + df as f64)).ln() + 1.0)
+            .collect();
+        let vectors = frequencies
+            .into_iter()
+            .map(|counts| Self::normalize(counts.into_iter().map(|(id, tf)| (id, tf as f64 * idf[id])).collect()))
+            .collect();
+
+        Self { terms, idf, vectors }
+    }
+
+    fn normalize(mut vector: Vector) -> Vector {
+        let norm = vector.iter().map(|(_, w)| w * w).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|(_, w)| *w /= norm);
+        }
+        vector.sort_by_key(|&(id, _)| id);
+        vector
+    }
+
+    /// TF-IDF vector of free text against the indexed vocabulary; unknown words are dropped
+    fn vectorize(&self, text: &str) -> Vector {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for (term, _) in analyze(text) {
+            if let Some(&id) = self.terms.get(&term) {
+                *counts.entry(id).or_insert(0) += 1;
+            }
+        }
+        Self::normalize(counts.into_iter().map(|(id, tf)| (id, tf as f64 * self.idf[id])).collect())
+    }
+
+    /// Cosine between free text and the project at `position`
+    pub fn text_similarity(&self, text: &str, position: usize) -> f64 {
+        self.vectors
+            .get(position)
+            .map(|vector| cosine(&self.vectorize(text), vector))
+            .unwrap_or(0.0)
+    }
+
+    /// Similarity of a described project, known only by keywords and whether its category
+    /// matches, to the project at `position`: text and category blended at their usual weights
+    pub fn keyword_similarity(&self, keywords: &str, category_matches: bool, position: usize) -> f64 {
+        let text = self.text_similarity(keywords, position);
+        (TEXT_WEIGHT * text + CATEGORY_WEIGHT * same(category_matches)) / (TEXT_WEIGHT + CATEGORY_WEIGHT)
+    }
+
+    /// Other projects ranked by blended similarity to the one at `position`, as positions
+    /// with their score and breakdown. Ties go to the lower position.
+    pub fn similar(
+        &self,
+        projects: &[Project],
+        position: usize,
+        limit: Option<usize>,
+    ) -> Vec<(usize, f64, SimilarityBreakdown)> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let Some(target) = projects.get(position) else {
+            return Vec::new();
+        };
+
+        let mut ranked: Vec<(usize, f64, SimilarityBreakdown)> = projects
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != position)
+            .map(|(other, project)| {
+                let mut breakdown = structured(target, project);
+                breakdown.text = cosine(&self.vectors[position], &self.vectors[other]);
+                (other, blended_score(&breakdown), breakdown)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn project(id: &str, category: &str, description: &str, tags: &[&str], soroban: bool) -> Project {
+        test_support::project(json!({
+            "title": id,
+            "category": category,
+            "description": description,
+            "tags": tags,
+            "soroban": soroban,
+        }))
+    }
+
+    #[test]
+    fn test_similar_ranks_by_text_and_structure() {
+        let projects = vec![
+            project("wallet", "Applications", "Mobile wallet for cross-border payments", &["payments", "wallet"], false),
+            project("remit", "Applications", "Cross-border remittance payments app", &["payments"], false),
+            project("oracle", "Infrastructure", "Price oracle feeds for Soroban contracts", &["oracle"], true),
+            project("pay-sdk", "Infrastructure", "SDK for payments", &["payments", "sdk"], true),
+        ];
+        let index = SimilarityIndex::build(&projects);
+
+        let similar = index.similar(&projects, 0, None);
+        assert_eq!(similar.len(), 3);
+        assert_eq!(projects[similar[0].0].id, "remit");
+        assert!(similar[0].2.text > 0.0);
+        assert_eq!(similar[0].2.category, 1.0);
+        assert_eq!(projects[similar[2].0].id, "oracle");
+        assert_eq!(similar[2].2.text, 0.0);
+        assert!(similar.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        assert!(index.text_similarity("remittance payments", 1) > index.text_similarity("remittance payments", 2));
+        assert_eq!(index.similar(&projects, 0, Some(1)).len(), 1);
+    }
+
+    #[test]
+    fn test_structure_breaks_text_ties() {
+        let with_chains = |id: &str, category: &str, chains: &str| {
+            test_support::project(json!({
+                "title": id,
+                "category": category,
+                "description": "Lending protocol for stablecoins",
+                "other_chains": chains,
+            }))
+        };
+        let projects = vec![
+            with_chains("source", "Financial Protocols", "Ethereum"),
+            with_chains("other-category", "Applications", "Ethereum"),
+            with_chains("no-chains", "Financial Protocols", ""),
+            with_chains("same-chains", "Financial Protocols", "Ethereum"),
+            with_chains("bare", "Financial Protocols", ""),
+        ];
+        let index = SimilarityIndex::build(&projects);
+
+        let similar = index.similar(&projects, 0, None);
+        let ids: Vec<&str> = similar.iter().map(|(i, _, _)| projects[*i].id.as_str()).collect();
+        assert!(similar.iter().all(|(_, _, b)| (b.text - similar[0].2.text).abs() < 1e-9));
+        assert_eq!(ids, ["same-chains", "no-chains", "bare", "other-category"]);
+
+        // Neither side lists a chain, so the pair gets no credit for it
+        let sparse = index.similar(&projects, 2, None);
+        let bare = sparse.iter().find(|(i, _, _)| projects[*i].id == "bare").unwrap();
+        assert_eq!(bare.2.chains, 0.0);
+    }
+}
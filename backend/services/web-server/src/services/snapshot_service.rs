@@ -0,0 +1,221 @@
+use shared::{DatasetLoadReport, DatasetSnapshot, FieldChange, Project, ProjectChange, SnapshotDiff};
+use lib_core::DataConfig;
+use lib_web::AppError;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::{services::{DatasetService, EnrichedService}, AppState, Dataset};
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+pub struct SnapshotService;
+
+impl SnapshotService {
+    /// SHA-256 of the source bytes, hex encoded
+    pub fn checksum(bytes: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(bytes);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Write the dataset to the snapshot archive and record it in the manifest
+    pub async fn archive(config: &DataConfig, dataset: &Dataset) -> Result<(), AppError> {
+        let dir = config.snapshots_dir();
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to create snapshot directory: {}", e)))?;
+
+        let mut manifest = Self::read_manifest(config).await?;
+        if manifest.iter().any(|s| s.id == dataset.snapshot.id) {
+            return Ok(());
+        }
+
+        let contents = serde_json::to_vec(&dataset.projects)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize snapshot: {}", e)))?;
+        tokio::fs::write(dir.join(format!("{}.json", dataset.snapshot.id)), contents)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to write snapshot: {}", e)))?;
+
+        manifest.push(dataset.snapshot.clone());
+        let manifest_json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize snapshot manifest: {}", e)))?;
+        tokio::fs::write(dir.join(MANIFEST_FILE), manifest_json)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to write snapshot manifest: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// All known snapshots, newest first
+    pub async fn list(state: &AppState) -> Result<Vec<DatasetSnapshot>, AppError> {
+        let mut snapshots = Self::read_manifest(&state.data_config).await?;
+
+        let current = state.dataset().snapshot.clone();
+        if snapshots.iter().all(|s| s.id != current.id) {
+            snapshots.push(current);
+        }
+
+        snapshots.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+        Ok(snapshots)
+    }
+
+    /// Find a snapshot in memory, falling back to the on-disk archive
+    pub async fn resolve(state: &AppState, id: &str) -> Result<Arc<Dataset>, AppError> {
+        if let Some(dataset) = state.retained_dataset(id) {
+            return Ok(dataset);
+        }
+
+        let not_found = || AppError::NotFound(format!("Snapshot {} not found", id));
+
+        // Snapshot ids are hex digests; anything else can't name an archive file
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(not_found());
+        }
+
+        let snapshot = Self::read_manifest(&state.data_config)
+            .await?
+            .into_iter()
+            .find(|s| s.id == id)
+            .ok_or_else(not_found)?;
+
+        let path = state.data_config.snapshots_dir().join(format!("{}.json", id));
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|_| not_found())?;
+        let mut projects: Vec<Project> = serde_json::from_str(&contents)
+            .map_err(|e| AppError::InternalError(format!("Failed to parse snapshot {}: {}", id, e)))?;
+        // Snapshots archived before projects carried ids get them derived the same way
+        DatasetService::assign_ids(&mut projects);
+
+        let mut report = DatasetLoadReport::new(path.display().to_string());
+        report.total_records = projects.len();
+        report.loaded_count = projects.len();
+
+        let mut dataset = Dataset::new(projects, report, snapshot.checksum.clone());
+        let enrichment = EnrichedService::load(&state.data_config.enriched_dir(), &dataset).await;
+        if snapshot.enrichment_checksum.as_ref().is_some_and(|archived| *archived != enrichment.checksum) {
+            warn!("Enrichment changed since snapshot {} was archived; serving the current files", id);
+        }
+        dataset.set_enrichment(enrichment);
+        // Keeps the enrichment checksum it was archived with
+        dataset.snapshot = snapshot;
+        let dataset = Arc::new(dataset);
+        state.retain_dataset(dataset.clone());
+
+        Ok(dataset)
+    }
+
+    /// Added, removed and field-level changed projects between two snapshots, keyed by project id
+    pub fn diff(from: &Dataset, to: &Dataset) -> SnapshotDiff {
+        let before: HashMap<&str, &Project> = from.projects.iter().map(|p| (p.id.as_str(), p)).collect();
+        let after: HashMap<&str, &Project> = to.projects.iter().map(|p| (p.id.as_str(), p)).collect();
+
+        let mut added: Vec<String> = after
+            .keys()
+            .filter(|id| !before.contains_key(*id))
+            .map(|id| id.to_string())
+            .collect();
+        added.sort();
+
+        let mut removed: Vec<String> = before
+            .keys()
+            .filter(|id| !after.contains_key(*id))
+            .map(|id| id.to_string())
+            .collect();
+        removed.sort();
+
+        let mut changed: Vec<ProjectChange> = after
+            .iter()
+            .filter_map(|(id, new)| {
+                let old = before.get(id)?;
+                let changes = Self::field_changes(old, new);
+                (!changes.is_empty()).then(|| ProjectChange {
+                    id: id.to_string(),
+                    title: new.title.clone(),
+                    changes,
+                })
+            })
+            .collect();
+        changed.sort_by(|a, b| a.id.cmp(&b.id));
+
+        SnapshotDiff {
+            from: from.snapshot.clone(),
+            to: to.snapshot.clone(),
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    fn field_changes(old: &Project, new: &Project) -> Vec<FieldChange> {
+        let (Ok(Value::Object(old)), Ok(Value::Object(new))) =
+            (serde_json::to_value(old), serde_json::to_value(new))
+        else {
+            return vec![];
+        };
+
+        let fields: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        fields
+            .into_iter()
+            .filter_map(|field| {
+                let before = old.get(field).cloned().unwrap_or(Value::Null);
+                let after = new.get(field).cloned().unwrap_or(Value::Null);
+                (before != after).then(|| FieldChange {
+                    field: field.clone(),
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+
+    async fn read_manifest(config: &DataConfig) -> Result<Vec<DatasetSnapshot>, AppError> {
+        match tokio::fs::read_to_string(config.snapshots_dir().join(MANIFEST_FILE)).await {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| AppError::InternalError(format!("Failed to parse snapshot manifest: {}", e))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(AppError::InternalError(format!("Failed to read snapshot manifest: {}", e))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dataset(projects: serde_json::Value, checksum: &str) -> Dataset {
+        let mut projects: Vec<Project> = serde_json::from_value(projects).unwrap();
+        DatasetService::assign_ids(&mut projects);
+        Dataset::new(projects, DatasetLoadReport::new("test.json"), checksum.to_string())
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed() {
+        let from = dataset(
+            serde_json::json!([
+                {"title": "Kept", "total_awarded": 50000.0, "integration_status": "Testnet"},
+                {"title": "Dropped"}
+            ]),
+            "aaaa",
+        );
+        let to = dataset(
+            serde_json::json!([
+                {"title": "Kept", "total_awarded": 150000.0, "integration_status": "Mainnet"},
+                {"title": "New"}
+            ]),
+            "bbbb",
+        );
+
+        let diff = SnapshotService::diff(&from, &to);
+
+        assert_eq!(diff.added, vec!["new".to_string()]);
+        assert_eq!(diff.removed, vec!["dropped".to_string()]);
+        assert_eq!(diff.changed.len(), 1);
+
+        let fields: Vec<&str> = diff.changed[0].changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["integration_status", "total_awarded"]);
+    }
+}
@@ -0,0 +1,286 @@
+use lib_web::AppError;
+use serde::{Deserialize, Serialize};
+use shared::{ParsedFields, Project, ProjectFilter, Quarter};
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::services::Enrichment;
+
+/// A field listings can be sorted on with `sort=field:dir,...`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    Funding,
+    Rounds,
+    /// Latest quarter the project was awarded in
+    Quarter,
+    Country,
+    /// `social_score` from the enriched social links
+    Social,
+    /// See `health_score`
+    Health,
+    Title,
+    /// Relevance, the order of a search without `sort`. A rank is a position in the results,
+    /// so cursors over it shift when the dataset changes.
+    Rank,
+}
+
+impl SortField {
+    const KEYS: [&'static str; 7] = ["funding", "rounds", "quarter", "country", "social", "health", "title"];
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "funding" => Some(Self::Funding),
+            "rounds" => Some(Self::Rounds),
+            "quarter" => Some(Self::Quarter),
+            "country" => Some(Self::Country),
+            "social" => Some(Self::Social),
+            "health" => Some(Self::Health),
+            "title" | "name" => Some(Self::Title),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Funding => "funding",
+            Self::Rounds => "rounds",
+            Self::Quarter => "quarter",
+            Self::Country => "country",
+            Self::Social => "social",
+            Self::Health => "health",
+            Self::Title => "title",
+            Self::Rank => "rank",
+        }
+    }
+
+    /// Largest first for numbers and quarters, A to Z for text
+    fn default_descending(&self) -> bool {
+        !matches!(self, Self::Country | Self::Title | Self::Rank)
+    }
+
+    fn value(&self, project: &Project, rank: usize, enrichment: &Enrichment) -> SortValue {
+        match self {
+            Self::Funding => SortValue::Number(project.get_funding_amount_numeric()),
+            Self::Rounds => SortValue::Number(Some(project.parsed.rounds.len() as f64)),
+            Self::Quarter => SortValue::Quarter(project.latest_quarter()),
+            Self::Country => SortValue::Text(project.country_name().map(str::to_lowercase)),
+            Self::Social => SortValue::Number(enrichment.social.get(&project.id).map(|s| s.social_score)),
+            Self::Health => SortValue::Number(Some(health_score(project, enrichment))),
+            Self::Title => SortValue::Text(Some(project.title.trim().to_lowercase())),
+            Self::Rank => SortValue::Rank(rank),
+        }
+    }
+}
+
+/// One component of a project's sort key; missing values sort last in either direction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SortValue {
+    Number(Option<f64>),
+    Text(Option<String>),
+    Quarter(Option<Quarter>),
+    Rank(usize),
+}
+
+impl SortValue {
+    fn compare(&self, other: &Self, descending: bool) -> Ordering {
+        fn nulls_last<T>(a: &Option<T>, b: &Option<T>, descending: bool, cmp: impl Fn(&T, &T) -> Ordering) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) if descending => cmp(b, a),
+                (Some(a), Some(b)) => cmp(a, b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => nulls_last(a, b, descending, |a, b| a.total_cmp(b)),
+            (Self::Text(a), Self::Text(b)) => nulls_last(a, b, descending, |a, b| a.cmp(b)),
+            (Self::Quarter(a), Self::Quarter(b)) => nulls_last(a, b, descending, |a, b| a.cmp(b)),
+            (Self::Rank(a), Self::Rank(b)) if descending => b.cmp(a),
+            (Self::Rank(a), Self::Rank(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// How mature a project looks, 0 to 1: the share of mainnet, open source, GitHub, website,
+/// reported traction, repeat funding and social links it has
+pub fn health_score(project: &Project, enrichment: &Enrichment) -> f64 {
+    let present = |value: Option<&String>| value.is_some_and(|v| !ParsedFields::is_placeholder(v));
+    let signals = [
+        project.is_mainnet(),
+        project.is_open_source(),
+        present(project.github.as_ref()),
+        present(project.website.as_ref()),
+        present(project.traction.as_ref()),
+        project.parsed.rounds.len() > 1,
+        enrichment.social.get(&project.id).is_some_and(|s| s.social_score > 0.0),
+    ];
+    signals.iter().filter(|&&signal| signal).count() as f64 / signals.len() as f64
+}
+
+/// The order of a listing: fields compared in turn, then project id so ties never depend
+/// on load order
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortSpec {
+    keys: Vec<(SortField, bool)>,
+}
+
+impl fmt::Display for SortSpec {
+    /// Canonical `field:dir,...` form, with every direction spelled out
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys: Vec<String> = self
+            .keys
+            .iter()
+            .map(|(field, descending)| format!("{}:{}", field.as_str(), if *descending { "desc" } else { "asc" }))
+            .collect();
+        write!(f, "{}", keys.join(","))
+    }
+}
+
+impl SortSpec {
+    /// `sort=funding:desc,rounds,title:asc`; a key without a direction takes its natural one
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        let mut keys: Vec<(SortField, bool)> = Vec::new();
+        for part in value.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (name, direction) = part.split_once(':').unwrap_or((part, ""));
+            let field = SortField::parse(name).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Unknown sort key `{}`; expected one of {}",
+                    name.trim(),
+                    SortField::KEYS.join(", ")
+                ))
+            })?;
+            let descending = match direction.trim().to_lowercase().as_str() {
+                "" => field.default_descending(),
+                "desc" => true,
+                "asc" => false,
+                other => {
+                    return Err(AppError::BadRequest(format!(
+                        "Unknown sort direction `{}` for `{}`; expected asc or desc",
+                        other,
+                        field.as_str()
+                    )))
+                }
+            };
+            if keys.iter().any(|(existing, _)| *existing == field) {
+                return Err(AppError::BadRequest(format!("Sort key `{}` is given twice", field.as_str())));
+            }
+            keys.push((field, descending));
+        }
+
+        if keys.is_empty() {
+            return Err(AppError::BadRequest("sort needs at least one key".to_string()));
+        }
+        Ok(Self { keys })
+    }
+
+    /// The filter's `sort`, or else its legacy `sort_by` spelled as a `sort`. Without either,
+    /// searches go by relevance and other listings by title, a value cursors can resume from.
+    pub fn from_filter(filter: &ProjectFilter) -> Result<Self, AppError> {
+        fn given(value: &Option<String>) -> Option<&str> {
+            value.as_deref().filter(|v| !v.trim().is_empty())
+        }
+        match (given(&filter.sort), given(&filter.sort_by)) {
+            (Some(sort), _) => Self::parse(sort),
+            (None, Some(sort_by)) => {
+                let sort = match sort_by.trim() {
+                    "funding_desc" => "funding:desc",
+                    "funding_asc" => "funding:asc",
+                    "name_asc" => "title:asc",
+                    "name_desc" => "title:desc",
+                    "recent" => "quarter:desc",
+                    other => {
+                        return Err(AppError::BadRequest(format!(
+                            "Unknown sort_by `{}`; expected funding_desc, funding_asc, name_asc, name_desc or recent",
+                            other
+                        )))
+                    }
+                };
+                Self::parse(sort)
+            }
+            (None, None) if filter.search_query.as_deref().is_some_and(|q| !q.trim().is_empty()) => {
+                Ok(Self { keys: vec![(SortField::Rank, false)] })
+            }
+            (None, None) => Ok(Self { keys: vec![(SortField::Title, false)] }),
+        }
+    }
+
+    /// The fields in order, each with whether it sorts descending
+    pub fn keys(&self) -> &[(SortField, bool)] {
+        &self.keys
+    }
+
+    /// Key for `project`; `rank` is its place in the unsorted listing
+    pub fn key(&self, project: &Project, rank: usize, enrichment: &Enrichment) -> Vec<SortValue> {
+        self.keys.iter().map(|(field, _)| field.value(project, rank, enrichment)).collect()
+    }
+
+    pub fn compare(&self, a: &[SortValue], b: &[SortValue]) -> Ordering {
+        self.keys
+            .iter()
+            .zip(a.iter().zip(b))
+            .map(|((_, descending), (a, b))| a.compare(b, *descending))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn project(id: &str, funding: Option<f64>, rounds: &str, country: Option<&str>) -> Project {
+        test_support::project(json!({
+            "title": id,
+            "total_awarded": funding,
+            "rounds": rounds,
+            "country": country,
+        }))
+    }
+
+    #[test]
+    fn test_parse_sort_spec() {
+        let spec = SortSpec::parse("funding:desc, rounds ,title:asc").unwrap();
+        assert_eq!(spec.to_string(), "funding:desc,rounds:desc,title:asc");
+        assert_eq!(SortSpec::parse("country").unwrap().to_string(), "country:asc");
+
+        assert!(SortSpec::parse("funding:up").is_err());
+        assert!(SortSpec::parse("popularity:desc").is_err());
+        assert!(SortSpec::parse("funding,funding:asc").is_err());
+        assert!(SortSpec::parse(" , ").is_err());
+
+        let legacy = ProjectFilter { sort_by: Some("recent".into()), ..Default::default() };
+        assert_eq!(SortSpec::from_filter(&legacy).unwrap().to_string(), "quarter:desc");
+        let unknown = ProjectFilter { sort_by: Some("popular".into()), ..Default::default() };
+        assert!(SortSpec::from_filter(&unknown).is_err());
+        let both = ProjectFilter { sort: Some("rounds".into()), ..Default::default() };
+        assert_eq!(SortSpec::from_filter(&both).unwrap().to_string(), "rounds:desc");
+    }
+
+    #[test]
+    fn test_multi_key_order_puts_missing_values_last() {
+        let projects = [
+            project("a", Some(100.0), "SCF #1", Some("Kenya")),
+            project("b", Some(100.0), "SCF #1, SCF #2", None),
+            project("c", None, "SCF #1", Some("Brazil")),
+            project("d", Some(50.0), "SCF #1", Some("Brazil")),
+        ];
+        let enrichment = Enrichment::default();
+        let sorted = |sort: &str| {
+            let spec = SortSpec::parse(sort).unwrap();
+            let mut keyed: Vec<(Vec<SortValue>, &Project)> =
+                projects.iter().enumerate().map(|(rank, p)| (spec.key(p, rank, &enrichment), p)).collect();
+            keyed.sort_by(|(a, _), (b, _)| spec.compare(a, b));
+            keyed.iter().map(|(_, p)| p.id.as_str()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(sorted("funding:desc,rounds:desc"), ["b", "a", "d", "c"]);
+        assert_eq!(sorted("funding:asc"), ["d", "a", "b", "c"]);
+        assert_eq!(sorted("country:asc,funding:desc"), ["d", "c", "a", "b"]);
+        assert_eq!(sorted("country:desc"), ["a", "c", "d", "b"]);
+    }
+}
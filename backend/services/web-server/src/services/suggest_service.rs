@@ -0,0 +1,249 @@
+use shared::{Project, SuggestResponse, Suggestion};
+use std::collections::{HashMap, HashSet};
+
+/// Suggestions per kind when the request sets no limit
+const DEFAULT_LIMIT: usize = 5;
+const MAX_LIMIT: usize = 20;
+
+/// Typos tolerated for a query of this many characters; short queries must match exactly
+fn max_typos(query_len: usize) -> usize {
+    match query_len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Lowercased alphanumeric words joined by single spaces, so punctuation and case never count as typos
+fn normalize(value: &str) -> Vec<char> {
+    value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+        .chars()
+        .collect()
+}
+
+/// Fewest edits turning `query` into some prefix of `candidate`, counting a swap of two
+/// neighbouring letters as one edit. `None` when more than `max` edits are needed.
+fn prefix_distance(query: &[char], candidate: &[char], max: usize) -> Option<usize> {
+    let candidate = &candidate[..candidate.len().min(query.len() + max)];
+    let width = candidate.len() + 1;
+    let mut rows: Vec<Vec<usize>> = vec![(0..width).collect()];
+
+    for (i, &q) in query.iter().enumerate() {
+        let mut row = vec![i + 1; width];
+        for (j, &c) in candidate.iter().enumerate() {
+            let substitution = rows[i][j] + usize::from(q != c);
+            row[j + 1] = substitution.min(rows[i][j + 1] + 1).min(row[j] + 1);
+            if i > 0 && j > 0 && q == candidate[j - 1] && query[i - 1] == c {
+                row[j + 1] = row[j + 1].min(rows[i - 1][j - 1] + 1);
+            }
+        }
+        if row.iter().min().is_some_and(|&best| best > max) {
+            return None;
+        }
+        rows.push(row);
+    }
+
+    rows.last()
+        .and_then(|row| row.iter().min().copied())
+        .filter(|&distance| distance <= max)
+}
+
+/// A value the query can complete to, with the popularity it is ranked by
+#[derive(Debug)]
+struct Entry {
+    value: String,
+    project_id: Option<String>,
+    normalized: Vec<char>,
+    project_count: usize,
+    total_funding: f64,
+}
+
+impl Entry {
+    /// Best match of the query against the value or any of its later words, so "pay" finds "Stellar Pay"
+    fn distance(&self, query: &[char], max: usize) -> Option<usize> {
+        let word_starts = (0..self.normalized.len())
+            .filter(|&i| i == 0 || self.normalized[i - 1] == ' ');
+
+        word_starts
+            .filter_map(|start| prefix_distance(query, &self.normalized[start..], max))
+            .min()
+    }
+
+    fn suggestion(&self, distance: usize) -> Suggestion {
+        Suggestion {
+            value: self.value.clone(),
+            project_id: self.project_id.clone(),
+            project_count: self.project_count,
+            total_funding: self.total_funding,
+            distance,
+        }
+    }
+}
+
+/// Distinct values across projects, keeping the first spelling of each normalized value
+fn group_values<F>(projects: &[Project], values: F) -> Vec<Entry>
+where
+    F: Fn(&Project) -> Vec<String>,
+{
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut positions: HashMap<Vec<char>, usize> = HashMap::new();
+
+    for project in projects {
+        let mut seen = HashSet::new();
+        for value in values(project) {
+            let normalized = normalize(&value);
+            if normalized.is_empty() || !seen.insert(normalized.clone()) {
+                continue;
+            }
+
+            let position = *positions.entry(normalized.clone()).or_insert_with(|| {
+                entries.push(Entry {
+                    value: value.trim().to_string(),
+                    project_id: None,
+                    normalized,
+                    project_count: 0,
+                    total_funding: 0.0,
+                });
+                entries.len() - 1
+            });
+            entries[position].project_count += 1;
+            entries[position].total_funding += project.get_funding_amount_numeric().unwrap_or(0.0);
+        }
+    }
+
+    entries
+}
+
+/// Autocomplete over project titles, companies, tags, countries and programs; built once per dataset load
+#[derive(Debug, Default)]
+pub struct SuggestIndex {
+    projects: Vec<Entry>,
+    companies: Vec<Entry>,
+    tags: Vec<Entry>,
+    countries: Vec<Entry>,
+    programs: Vec<Entry>,
+}
+
+impl SuggestIndex {
+    pub fn build(projects: &[Project]) -> Self {
+        Self {
+            projects: projects
+                .iter()
+                .map(|project| Entry {
+                    value: project.title.clone(),
+                    project_id: Some(project.id.clone()),
+                    normalized: normalize(&project.title),
+                    project_count: 1,
+                    total_funding: project.get_funding_amount_numeric().unwrap_or(0.0),
+                })
+                .collect(),
+            companies: group_values(projects, |p| p.company.iter().cloned().collect()),
+            tags: group_values(projects, |p| p.tags.clone()),
+            countries: group_values(projects, |p| p.country_name().map(str::to_string).into_iter().collect()),
+            programs: group_values(projects, |p| {
+                p.parsed.programs.iter().map(|program| program.as_str().to_string()).collect()
+            }),
+        }
+    }
+
+    /// Prefix matches first, then matches with typos; ties go to the value with more
+    /// projects, then more funding
+    pub fn suggest(&self, query: &str, limit: Option<usize>) -> SuggestResponse {
+        let normalized = normalize(query);
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        let rank = |entries: &[Entry]| -> Vec<Suggestion> {
+            if normalized.is_empty() {
+                return Vec::new();
+            }
+            let max = max_typos(normalized.len());
+
+            let mut matches: Vec<(usize, &Entry)> = entries
+                .iter()
+                .filter_map(|entry| entry.distance(&normalized, max).map(|distance| (distance, entry)))
+                .collect();
+            matches.sort_by(|(a_distance, a), (b_distance, b)| {
+                a_distance
+                    .cmp(b_distance)
+                    .then_with(|| b.project_count.cmp(&a.project_count))
+                    .then_with(|| {
+                        b.total_funding
+                            .partial_cmp(&a.total_funding)
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .then_with(|| a.value.cmp(&b.value))
+            });
+
+            matches
+                .into_iter()
+                .take(limit)
+                .map(|(distance, entry)| entry.suggestion(distance))
+                .collect()
+        };
+
+        SuggestResponse {
+            query: query.to_string(),
+            projects: rank(&self.projects),
+            companies: rank(&self.companies),
+            tags: rank(&self.tags),
+            countries: rank(&self.countries),
+            programs: rank(&self.programs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn project(title: &str, company: &str, tags: &[&str], funding: f64) -> Project {
+        test_support::project(json!({
+            "title": title,
+            "company": company,
+            "total_awarded": funding,
+            "tags": tags,
+        }))
+    }
+
+    #[test]
+    fn test_prefix_distance_tolerates_typos() {
+        let distance = |query: &str, candidate: &str, max| {
+            prefix_distance(&normalize(query), &normalize(candidate), max)
+        };
+
+        assert_eq!(distance("sor", "Soroban", 0), Some(0));
+        assert_eq!(distance("sorban", "Soroban", 1), Some(1));
+        assert_eq!(distance("sorbo", "Soroban", 1), Some(1));
+        assert_eq!(distance("sorban", "Stellar", 1), None);
+    }
+
+    #[test]
+    fn test_suggest_groups_and_ranks() {
+        let projects = vec![
+            project("Stellar Pay", "Paystream Labs", &["payments", "soroban"], 20_000.0),
+            project("Payroll Hub", "Hub Inc", &["payments"], 90_000.0),
+            project("Soroban Studio", "Studio Co", &["soroban", "tooling"], 50_000.0),
+        ];
+        let index = SuggestIndex::build(&projects);
+
+        let suggestions = index.suggest("pay", None);
+        let titles: Vec<&str> = suggestions.projects.iter().map(|s| s.value.as_str()).collect();
+        assert_eq!(titles, ["Payroll Hub", "Stellar Pay"]);
+        assert_eq!(suggestions.companies[0].value, "Paystream Labs");
+        assert_eq!(suggestions.tags[0].project_count, 2);
+
+        let suggestions = index.suggest("sorban", None);
+        assert_eq!(suggestions.projects[0].value, "Soroban Studio");
+        assert_eq!(suggestions.tags[0].value, "soroban");
+        assert_eq!(suggestions.tags[0].distance, 1);
+
+        assert!(index.suggest("  ", None).tags.is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+use serde_json::Value;
+use shared::{slugify, Project};
+
+/// A parsed project from projects-file fields; without an `id` it gets its title's slug
+pub fn project(fields: Value) -> Project {
+    let mut project: Project = serde_json::from_value(fields).expect("project fixture");
+    if project.id.is_empty() {
+        project.id = slugify(&project.title);
+    }
+    project.parse_fields();
+    project
+}
@@ -0,0 +1,196 @@
+use lib_web::AppError;
+use shared::{CategoryTimeline, Project, Quarter, TimelineData, TimelineResponse};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// How a timeline buckets award quarters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    #[default]
+    Quarter,
+    Year,
+}
+
+impl Granularity {
+    /// `quarter` (the default) or `year`
+    pub fn parse(value: Option<&str>) -> Result<Self, AppError> {
+        match value.map(|v| v.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("quarter") => Ok(Self::Quarter),
+            Some("year") => Ok(Self::Year),
+            Some(other) => Err(AppError::BadRequest(format!(
+                "Unknown granularity `{}`; expected quarter or year",
+                other
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Quarter => "quarter",
+            Self::Year => "year",
+        }
+    }
+
+    /// The period a quarter falls in; consecutive periods have consecutive keys
+    fn key(&self, quarter: Quarter) -> u32 {
+        match self {
+            Self::Quarter => quarter.ordinal(),
+            Self::Year => quarter.year() as u32,
+        }
+    }
+
+    fn label(&self, key: u32) -> String {
+        match self {
+            Self::Quarter => Quarter::from_ordinal(key).label(),
+            Self::Year => key.to_string(),
+        }
+    }
+}
+
+/// Each quarter a project was awarded in, earliest first, with an even share of its total
+/// funding; per-round amounts are not in the data
+pub fn awards(project: &Project) -> impl Iterator<Item = (Quarter, f64)> + '_ {
+    let quarters = &project.parsed.quarters;
+    let share = project.get_funding_amount_numeric().unwrap_or(0.0) / quarters.len().max(1) as f64;
+    quarters.iter().map(move |&quarter| (quarter, share))
+}
+
+pub struct TimelineService;
+
+impl TimelineService {
+    /// Awards per period across the projects, overall and per category
+    pub fn timeline(projects: &[&Project], granularity: Granularity) -> TimelineResponse {
+        let (dated, undated): (Vec<&Project>, Vec<&Project>) =
+            projects.iter().copied().partition(|p| !p.parsed.quarters.is_empty());
+
+        let first = dated.iter().filter_map(|p| p.first_quarter()).min();
+        let last = dated.iter().filter_map(|p| p.latest_quarter()).max();
+        let range = match (first, last) {
+            (Some(first), Some(last)) => granularity.key(first)..=granularity.key(last),
+            _ => {
+                return TimelineResponse {
+                    granularity: granularity.as_str().to_string(),
+                    timeline: Vec::new(),
+                    categories: Vec::new(),
+                    undated_projects: undated.len(),
+                }
+            }
+        };
+
+        let mut by_category: HashMap<String, Vec<&Project>> = HashMap::new();
+        for project in &dated {
+            by_category.entry(project.get_category().as_str().to_string()).or_default().push(project);
+        }
+        let mut categories: Vec<CategoryTimeline> = by_category
+            .into_iter()
+            .map(|(category, projects)| {
+                let timeline = Self::series(&projects, granularity, range.clone());
+                CategoryTimeline {
+                    category,
+                    total_funding: timeline.last().map(|period| period.cumulative_funding).unwrap_or(0.0),
+                    timeline,
+                }
+            })
+            .collect();
+        categories.sort_by(|a, b| {
+            b.total_funding.total_cmp(&a.total_funding).then_with(|| a.category.cmp(&b.category))
+        });
+
+        TimelineResponse {
+            granularity: granularity.as_str().to_string(),
+            timeline: Self::series(&dated, granularity, range),
+            categories,
+            undated_projects: undated.len(),
+        }
+    }
+
+    /// One entry per period in `keys`, with running totals
+    fn series(projects: &[&Project], granularity: Granularity, keys: RangeInclusive<u32>) -> Vec<TimelineData> {
+        let start = *keys.start();
+        let mut timeline: Vec<TimelineData> = keys
+            .map(|key| TimelineData {
+                period: granularity.label(key),
+                project_count: 0,
+                total_funding: 0.0,
+                new_projects: 0,
+                cumulative_projects: 0,
+                cumulative_funding: 0.0,
+            })
+            .collect();
+
+        for project in projects {
+            let mut previous = None;
+            for (quarter, share) in awards(project) {
+                let key = granularity.key(quarter);
+                let Some(period) = timeline.get_mut((key - start) as usize) else {
+                    continue;
+                };
+                period.total_funding += share;
+                // Quarters come sorted, so a repeated key is the same year again
+                if previous != Some(key) {
+                    period.project_count += 1;
+                    if previous.is_none() {
+                        period.new_projects += 1;
+                    }
+                }
+                previous = Some(key);
+            }
+        }
+
+        let (mut projects_so_far, mut funding_so_far) = (0, 0.0);
+        for period in &mut timeline {
+            projects_so_far += period.new_projects;
+            funding_so_far += period.total_funding;
+            period.cumulative_projects = projects_so_far;
+            period.cumulative_funding = funding_so_far;
+        }
+        timeline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support;
+    use serde_json::json;
+
+    fn project(category: &str, funding: f64, quarters: &str) -> Project {
+        test_support::project(json!({
+            "title": format!("{} {}", category, quarters),
+            "category": category,
+            "total_awarded": funding,
+            "quarters": quarters,
+        }))
+    }
+
+    #[test]
+    fn test_timeline_splits_funding_across_award_quarters() {
+        let projects = [
+            project("Infrastructure", 100.0, "Q1 '23, Q3 '23"),
+            project("Applications", 30.0, "Q3 '23"),
+            project("Applications", 80.0, "Q1 '24"),
+            project("Applications", 10.0, ""),
+        ];
+        let refs: Vec<&Project> = projects.iter().collect();
+
+        let quarterly = TimelineService::timeline(&refs, Granularity::Quarter);
+        let periods: Vec<&str> = quarterly.timeline.iter().map(|t| t.period.as_str()).collect();
+        assert_eq!(periods, ["Q1 '23", "Q2 '23", "Q3 '23", "Q4 '23", "Q1 '24"]);
+        assert_eq!(quarterly.timeline[2].project_count, 2);
+        assert_eq!(quarterly.timeline[2].new_projects, 1);
+        assert_eq!(quarterly.timeline[2].total_funding, 80.0);
+        assert_eq!(quarterly.timeline[4].cumulative_projects, 3);
+        assert_eq!(quarterly.timeline[4].cumulative_funding, 210.0);
+        assert_eq!(quarterly.undated_projects, 1);
+        assert_eq!(quarterly.categories[0].category, "Applications");
+        assert_eq!(quarterly.categories[0].total_funding, 110.0);
+        assert_eq!(quarterly.categories[1].timeline.len(), 5);
+
+        let yearly = TimelineService::timeline(&refs, Granularity::Year);
+        assert_eq!(yearly.timeline[0].period, "2023");
+        assert_eq!(yearly.timeline[0].project_count, 2);
+        assert_eq!(yearly.timeline[0].total_funding, 130.0);
+
+        assert!(Granularity::parse(Some("month")).is_err());
+    }
+}
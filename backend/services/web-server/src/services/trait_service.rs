@@ -0,0 +1,113 @@
+use lib_web::AppError;
+use shared::{ParsedFields, Project};
+
+use crate::services::Enrichment;
+
+/// A yes/no characteristic of a project that analyses compare funding across
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectTrait {
+    Soroban,
+    Mainnet,
+    OpenSource,
+    Github,
+    Multichain,
+    /// Funded in more than one round
+    MultipleRounds,
+    /// Reported traction
+    Traction,
+    /// The enriched website has documentation
+    Docs,
+    /// The enriched website has a blog
+    Blog,
+    /// The enriched website has a team page
+    TeamPage,
+    /// The enriched website has pricing
+    Pricing,
+}
+
+impl ProjectTrait {
+    pub const ALL: [Self; 11] = [
+        Self::Soroban,
+        Self::Mainnet,
+        Self::OpenSource,
+        Self::Github,
+        Self::Multichain,
+        Self::MultipleRounds,
+        Self::Traction,
+        Self::Docs,
+        Self::Blog,
+        Self::TeamPage,
+        Self::Pricing,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Soroban => "Soroban",
+            Self::Mainnet => "Live on mainnet",
+            Self::OpenSource => "Open source",
+            Self::Github => "GitHub",
+            Self::Multichain => "Multichain",
+            Self::MultipleRounds => "Multiple rounds",
+            Self::Traction => "Reported traction",
+            Self::Docs => "Website docs",
+            Self::Blog => "Website blog",
+            Self::TeamPage => "Website team page",
+            Self::Pricing => "Website pricing",
+        }
+    }
+
+    /// The name requests use, such as `open_source`
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Soroban => "soroban",
+            Self::Mainnet => "mainnet",
+            Self::OpenSource => "open_source",
+            Self::Github => "github",
+            Self::Multichain => "multichain",
+            Self::MultipleRounds => "multiple_rounds",
+            Self::Traction => "traction",
+            Self::Docs => "docs",
+            Self::Blog => "blog",
+            Self::TeamPage => "team_page",
+            Self::Pricing => "pricing",
+        }
+    }
+
+    /// A key or label, ignoring case, spaces and dashes
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        let normalized = value.trim().to_lowercase().replace([' ', '-'], "_");
+        Self::ALL
+            .into_iter()
+            .find(|t| t.key() == normalized || t.label().to_lowercase().replace(' ', "_") == normalized)
+            .ok_or_else(|| {
+                let keys: Vec<&str> = Self::ALL.iter().map(|t| t.key()).collect();
+                AppError::BadRequest(format!("Unknown trait `{}`; expected one of {}", value, keys.join(", ")))
+            })
+    }
+
+    pub fn has(&self, project: &Project, enrichment: &Enrichment) -> bool {
+        let present = |value: Option<&String>| value.is_some_and(|v| !ParsedFields::is_placeholder(v));
+        let sections = || enrichment.website.get(&project.id).map(|w| &w.sections);
+        match self {
+            Self::Soroban => project.uses_soroban(),
+            Self::Mainnet => project.is_mainnet(),
+            Self::OpenSource => project.is_open_source(),
+            Self::Github => present(project.github.as_ref()),
+            Self::Multichain => project.is_multichain(),
+            Self::MultipleRounds => project.parsed.rounds.len() > 1,
+            Self::Traction => present(project.traction.as_ref()),
+            Self::Docs => sections().is_some_and(|s| s.has_docs),
+            Self::Blog => sections().is_some_and(|s| s.has_blog),
+            Self::TeamPage => sections().is_some_and(|s| s.has_team),
+            Self::Pricing => sections().is_some_and(|s| s.has_pricing),
+        }
+    }
+
+    /// Share of the projects with this trait, 0 to 1; 0 for none
+    pub fn prevalence(&self, projects: &[&Project], enrichment: &Enrichment) -> f64 {
+        if projects.is_empty() {
+            return 0.0;
+        }
+        projects.iter().filter(|p| self.has(p, enrichment)).count() as f64 / projects.len() as f64
+    }
+}
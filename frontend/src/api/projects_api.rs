@@ -1,12 +1,12 @@
-use shared::{Project, ProjectsResponse, ProjectSearchRequest};
+use shared::{ProjectResponse, ProjectSummary, ProjectsResponse, ProjectSearchRequest};
 use super::client;
 
 pub async fn list_projects(
     page: Option<usize>,
     per_page: Option<usize>,
     category: Option<String>,
-) -> Result<ProjectsResponse, String> {
-    let mut url = "/api/projects?".to_string();
+) -> Result<ProjectsResponse<ProjectSummary>, String> {
+    let mut url = "/api/projects?fields=summary&".to_string();
 
     if let Some(p) = page {
         url.push_str(&format!("page={}&", p));
@@ -21,10 +21,10 @@ pub async fn list_projects(
     client::get(&url).await
 }
 
-pub async fn get_project(id: &str) -> Result<Project, String> {
+pub async fn get_project(id: &str) -> Result<ProjectResponse, String> {
     client::get(&format!("/api/projects/{}", id)).await
 }
 
-pub async fn search_projects(request: ProjectSearchRequest) -> Result<Vec<Project>, String> {
+pub async fn search_projects(request: ProjectSearchRequest) -> Result<Vec<ProjectResponse>, String> {
     client::post("/api/projects/search", &request).await
 }
@@ -28,63 +28,73 @@ pub fn funding_by_round() -> Html {
             wasm_bindgen_futures::spawn_local(async move {
                 loading.set(true);
 
-                // Calculate from projects list
-                match Request::get("http://localhost:3000/api/projects?per_page=1000")
-                    .send()
-                    .await
-                {
-                    Ok(resp) => {
-                        if let Ok(json) = resp.json::<serde_json::Value>().await {
-                            if let Some(projects) = json["data"]["projects"].as_array() {
-                                let mut round_map: std::collections::HashMap<String, (f64, usize)> = std::collections::HashMap::new();
-
-                                for project in projects {
-                                    if let (Some(rounds_str), Some(total)) = (
-                                        project["rounds"].as_str(),
-                                        project["total_awarded"].as_f64()
-                                    ) {
-                                        let rounds: Vec<&str> = rounds_str.split(',').collect();
-                                        let funding_per_round = total / rounds.len() as f64;
-
-                                        for round in rounds {
-                                            let round_clean = round.trim().to_string();
-                                            round_map.entry(round_clean)
-                                                .and_modify(|(f, c)| { *f += funding_per_round; *c += 1; })
-                                                .or_insert((funding_per_round, 1));
-                                        }
-                                    }
-                                }
+                // Calculate from projects list, a page of at most 100 at a time
+                let mut projects: Vec<serde_json::Value> = Vec::new();
+                let mut cursor: Option<String> = None;
+                loop {
+                    let mut url = "http://localhost:3000/api/projects?fields=rounds,total_awarded&per_page=100".to_string();
+                    if let Some(cursor) = &cursor {
+                        url.push_str(&format!("&cursor={}", cursor));
+                    }
+                    let Ok(resp) = Request::get(&url).send().await else {
+                        break;
+                    };
+                    let Ok(json) = resp.json::<serde_json::Value>().await else {
+                        break;
+                    };
+                    if let Some(page) = json["data"]["projects"].as_array() {
+                        projects.extend(page.iter().cloned());
+                    }
+                    match json["data"]["next_cursor"].as_str() {
+                        Some(next) => cursor = Some(next.to_string()),
+                        None => break,
+                    }
+                }
 
-                                let mut rounds: Vec<RoundFunding> = round_map
-                                    .into_iter()
-                                    .map(|(round, (funding, count))| RoundFunding {
-                                        round,
-                                        total_funding: funding,
-                                        project_count: count,
-                                        avg_funding: funding / count as f64,
-                                    })
-                                    .collect();
-
-                                // Sort by round number
-                                rounds.sort_by(|a, b| {
-                                    let a_num = a.round.split('#').last()
-                                        .and_then(|s| s.trim().parse::<u32>().ok())
-                                        .unwrap_or(0);
-                                    let b_num = b.round.split('#').last()
-                                        .and_then(|s| s.trim().parse::<u32>().ok())
-                                        .unwrap_or(0);
-                                    a_num.cmp(&b_num)
-                                });
-
-                                data.set(Some(rounds));
+                if !projects.is_empty() {
+                    let mut round_map: std::collections::HashMap<String, (f64, usize)> = std::collections::HashMap::new();
+
+                    for project in &projects {
+                        if let (Some(rounds_str), Some(total)) = (
+                            project["rounds"].as_str(),
+                            project["total_awarded"].as_f64()
+                        ) {
+                            let rounds: Vec<&str> = rounds_str.split(',').collect();
+                            let funding_per_round = total / rounds.len() as f64;
+
+                            for round in rounds {
+                                let round_clean = round.trim().to_string();
+                                round_map.entry(round_clean)
+                                    .and_modify(|(f, c)| { *f += funding_per_round; *c += 1; })
+                                    .or_insert((funding_per_round, 1));
                             }
                         }
-                        loading.set(false);
-                    }
-                    Err(_) => {
-                        loading.set(false);
                     }
+
+                    let mut rounds: Vec<RoundFunding> = round_map
+                        .into_iter()
+                        .map(|(round, (funding, count))| RoundFunding {
+                            round,
+                            total_funding: funding,
+                            project_count: count,
+                            avg_funding: funding / count as f64,
+                        })
+                        .collect();
+
+                    // Sort by round number
+                    rounds.sort_by(|a, b| {
+                        let a_num = a.round.split('#').last()
+                            .and_then(|s| s.trim().parse::<u32>().ok())
+                            .unwrap_or(0);
+                        let b_num = b.round.split('#').last()
+                            .and_then(|s| s.trim().parse::<u32>().ok())
+                            .unwrap_or(0);
+                        a_num.cmp(&b_num)
+                    });
+
+                    data.set(Some(rounds));
                 }
+                loading.set(false);
             });
         });
     }
@@ -49,7 +49,7 @@ pub fn gap_analysis_component() -> Html {
                 <>
                     <div class="gap-header">
                         <h2>{"GAP ANALYSIS EXPLORER"}</h2>
-                        <p class="text-secondary">{"Discover underserved categories with high funding potential"}</p>
+                        <p class="text-secondary">{"Category, region and technology niches with dense funding and little competition"}</p>
                     </div>
 
                     <div class="opportunities-table mt-4">
@@ -57,6 +57,8 @@ pub fn gap_analysis_component() -> Html {
                             <thead>
                                 <tr>
                                     <th>{"Category"}</th>
+                                    <th>{"Region"}</th>
+                                    <th>{"Technology"}</th>
                                     <th>{"Opportunity"}</th>
                                     <th>{"Competition"}</th>
                                     <th>{"Avg Funding"}</th>
@@ -87,6 +89,8 @@ pub fn gap_analysis_component() -> Html {
                                         html! {
                                             <tr>
                                                 <td><strong>{&opp.category}</strong></td>
+                                                <td>{&opp.region}</td>
+                                                <td>{&opp.technology}</td>
                                                 <td>{&opp.market_need}</td>
                                                 <td>
                                                     <span class={format!("badge badge-{}", if competition_level == "Low" { "success" } else if competition_level == "Medium" { "warning" } else { "danger" })}>
@@ -115,7 +119,7 @@ pub fn gap_analysis_component() -> Html {
                                 data.opportunities.iter().take(3).map(|opp| {
                                     html! {
                                         <div class="insight-card">
-                                            <h4>{&opp.category}</h4>
+                                            <h4>{format!("{} · {} · {}", opp.category, opp.region, opp.technology)}</h4>
                                             <p class="insight-reason">{&opp.strategy}</p>
                                             <div class="insight-meta">
                                                 <span>{format!("{} projects", opp.project_count)}</span>
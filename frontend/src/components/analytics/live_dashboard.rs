@@ -103,7 +103,7 @@ pub fn live_dashboard() -> Html {
                                         <div class="trending-card">
                                             <div class="trending-header">
                                                 <h4>{&cat.category}</h4>
-                                                <span class="growth-badge">{format!("+{:.1}%", cat.growth_rate)}</span>
+                                                <span class="growth-badge">{format!("{:+.1}%", cat.growth_rate)}</span>
                                             </div>
                                             <div class="trending-stats">
                                                 <div class="stat-item">
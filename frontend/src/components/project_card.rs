@@ -1,11 +1,11 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
-use shared::Project;
+use shared::ProjectSummary;
 use crate::router::Route;
 
 #[derive(Properties, PartialEq)]
 pub struct ProjectCardProps {
-    pub project: Project,
+    pub project: ProjectSummary,
 }
 
 #[function_component(ProjectCard)]
@@ -29,9 +29,9 @@ pub fn project_card(props: &ProjectCardProps) -> Html {
 
             <div class="project-card-body">
                 {
-                    if let Some(desc) = &project.description {
+                    if let Some(excerpt) = &project.excerpt {
                         html! {
-                            <p class="project-description">{desc}</p>
+                            <p class="project-description">{excerpt}</p>
                         }
                     } else {
                         html! {}
@@ -68,7 +68,7 @@ pub fn project_card(props: &ProjectCardProps) -> Html {
             </div>
 
             <div class="project-card-footer">
-                <Link<Route> to={Route::ProjectDetail { id: project.title.clone() }} classes="btn btn-sm btn-primary">
+                <Link<Route> to={Route::ProjectDetail { id: project.id.clone() }} classes="btn btn-sm btn-primary">
                     {"View Details"}
                 </Link<Route>>
             </div>
@@ -27,7 +27,7 @@ pub fn project_detail(props: &Props) -> Html {
 
                 match projects_api::get_project(&id).await {
                     Ok(data) => {
-                        project.set(Some(data));
+                        project.set(Some(data.project));
                         loading.set(false);
                     }
                     Err(e) => {
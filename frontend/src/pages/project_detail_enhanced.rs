@@ -3,7 +3,9 @@ use wasm_bindgen_futures::spawn_local;
 use gloo_net::http::Request;
 use serde_json::Value;
 use crate::components::layout::Layout;
-use shared::{Project, EnrichedProjectData};
+use yew_router::prelude::*;
+use crate::router::Route;
+use shared::{Project, EnrichedProjectData, SimilarProject, SimilarProjectsResponse};
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
@@ -16,6 +18,7 @@ pub fn project_detail_enhanced(props: &Props) -> Html {
     let enriched = use_state(|| Option::<EnrichedProjectData>::None);
     let loading = use_state(|| true);
     let error = use_state(|| Option::<String>::None);
+    let similar = use_state(Vec::<SimilarProject>::new);
 
     {
         let project = project.clone();
@@ -59,6 +62,26 @@ pub fn project_detail_enhanced(props: &Props) -> Html {
         });
     }
 
+    {
+        let similar = similar.clone();
+        let id = props.id.clone();
+
+        use_effect_with(id.clone(), move |_| {
+            spawn_local(async move {
+                let url = format!("/api/projects/{}/similar?limit=5", id);
+                if let Ok(response) = Request::get(&url).send().await {
+                    if let Ok(data) = response.json::<Value>().await {
+                        if let Some(response_data) = data.get("data") {
+                            if let Ok(r) = serde_json::from_value::<SimilarProjectsResponse>(response_data.clone()) {
+                                similar.set(r.similar);
+                            }
+                        }
+                    }
+                }
+            });
+        });
+    }
+
     html! {
         <Layout>
             <div class="project-detail-enhanced">
@@ -524,6 +547,37 @@ pub fn project_detail_enhanced(props: &Props) -> Html {
                                             }
                                         </div>
                                     </div>
+
+                                    // Similar Projects
+                                    if !similar.is_empty() {
+                                        <div class="info-card similar-card">
+                                            <h3 class="card-title">{"SIMILAR PROJECTS"}</h3>
+                                            <div class="similar-projects">
+                                                { for similar.iter().map(|s| {
+                                                    let b = &s.breakdown;
+                                                    let reasons: Vec<&str> = [
+                                                        (b.text >= 0.3, "description"),
+                                                        (b.category == 1.0, "category"),
+                                                        (b.project_type == 1.0, "type"),
+                                                        (b.country == 1.0, "country"),
+                                                    ]
+                                                    .into_iter()
+                                                    .filter(|(matched, _)| *matched)
+                                                    .map(|(_, reason)| reason)
+                                                    .collect();
+                                                    html! {
+                                                        <div class="similar-project">
+                                                            <Link<Route> to={Route::ProjectDetail { id: s.project.id.clone() }}>
+                                                                {&s.project.title}
+                                                            </Link<Route>>
+                                                            <span class="similar-score">{format!("{:.0}%", s.score * 100.0)}</span>
+                                                            <span class="text-muted">{reasons.join(" · ")}</span>
+                                                        </div>
+                                                    }
+                                                }) }
+                                            </div>
+                                        </div>
+                                    }
                                 </div>
                             </div>
                         </div>
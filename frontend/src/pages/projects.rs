@@ -2,11 +2,11 @@ use yew::prelude::*;
 use crate::components::layout::Layout;
 use crate::components::project_card::ProjectCard;
 use crate::api::projects_api;
-use shared::ProjectsResponse;
+use shared::{ProjectSummary, ProjectsResponse};
 
 #[function_component(Projects)]
 pub fn projects() -> Html {
-    let projects_data = use_state(|| Option::<ProjectsResponse>::None);
+    let projects_data = use_state(|| Option::<ProjectsResponse<ProjectSummary>>::None);
     let loading = use_state(|| true);
     let page = use_state(|| 1usize);
 
@@ -4,15 +4,17 @@ use gloo_net::http::Request;
 use serde_json::Value;
 use crate::components::layout::Layout;
 use crate::components::project_card::ProjectCard;
-use shared::{ProjectsResponse, ProjectFilter};
+use shared::{FacetCount, ProjectSummary, ProjectsResponse, ProjectFilter, SuggestResponse, Suggestion};
 
 #[function_component(ProjectsFiltered)]
 pub fn projects_filtered() -> Html {
-    let projects_data = use_state(|| Option::<ProjectsResponse>::None);
+    let projects_data = use_state(|| Option::<ProjectsResponse<ProjectSummary>>::None);
     let loading = use_state(|| true);
 
     // Filter states
     let search_query = use_state(|| String::new());
+    let search_input = use_state(|| String::new());
+    let suggestions = use_state(|| Option::<SuggestResponse>::None);
     let selected_categories = use_state(|| Vec::<String>::new());
     let funding_tier = use_state(|| String::from("all"));
     let soroban_only = use_state(|| false);
@@ -22,8 +24,11 @@ pub fn projects_filtered() -> Html {
     let selected_countries = use_state(|| Vec::<String>::new());
     let selected_programs = use_state(|| Vec::<String>::new());
     let min_rounds = use_state(|| 0u32);
-    let sort_by = use_state(|| String::from("funding_desc"));
+    let sort_by = use_state(|| String::from("funding:desc"));
     let page = use_state(|| 1usize);
+    let query_input = use_state(|| String::new());
+    let query = use_state(|| String::new());
+    let query_error = use_state(|| Option::<String>::None);
     let show_advanced = use_state(|| false);
 
     // Fetch projects with current filters
@@ -42,19 +47,25 @@ pub fn projects_filtered() -> Html {
         let rounds = *min_rounds;
         let sort = (*sort_by).clone();
         let current_page = *page;
+        let query_error = query_error.clone();
+        let q = (*query).clone();
 
-        use_effect_with((search.clone(), cats.clone(), tier.clone(), soroban, stellar, mainnet, github, countries.clone(), programs.clone(), rounds, sort.clone(), current_page), move |_| {
+        use_effect_with(((search.clone(), cats.clone(), tier.clone(), soroban, stellar, mainnet, github, countries.clone(), programs.clone(), rounds, sort.clone(), current_page), q.clone()), move |_| {
             spawn_local(async move {
                 loading.set(true);
 
                 let mut filter = ProjectFilter::default();
                 filter.page = Some(current_page);
                 filter.per_page = Some(12);
-                filter.sort_by = Some(sort.clone());
+                filter.sort = Some(sort.clone());
+                filter.sort_by = None;
 
                 if !search.is_empty() {
                     filter.search_query = Some(search);
                 }
+                if !q.trim().is_empty() {
+                    filter.q = Some(q);
+                }
                 if !cats.is_empty() {
                     filter.categories = Some(cats);
                 }
@@ -85,9 +96,18 @@ pub fn projects_filtered() -> Html {
                     Ok(response) => {
                         if let Ok(data) = response.json::<Value>().await {
                             if let Some(response_data) = data.get("data") {
-                                if let Ok(projects_resp) = serde_json::from_value::<ProjectsResponse>(response_data.clone()) {
+                                if let Ok(projects_resp) = serde_json::from_value::<ProjectsResponse<ProjectSummary>>(response_data.clone()) {
                                     projects_data.set(Some(projects_resp));
                                 }
+                                query_error.set(None);
+                            } else if let Some(error) = data.get("error") {
+                                // Query language errors point at the offending characters
+                                let message = error.get("message").and_then(Value::as_str).unwrap_or("Invalid query");
+                                let position = error.pointer("/details/position").and_then(Value::as_u64);
+                                query_error.set(Some(match position {
+                                    Some(position) => format!("{} (character {})", message, position + 1),
+                                    None => message.to_string(),
+                                }));
                             }
                         }
                         loading.set(false);
@@ -101,18 +121,59 @@ pub fn projects_filtered() -> Html {
         });
     }
 
-    // Search input handler
+    // Search input handler: typing only fetches suggestions, the list reloads on submit
     let on_search = {
+        let search_input = search_input.clone();
         let search_query = search_query.clone();
+        let suggestions = suggestions.clone();
         let page = page.clone();
         Callback::from(move |e: InputEvent| {
             if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
-                search_query.set(input.value());
-                page.set(1); // Reset to first page on search
+                let value = input.value();
+                search_input.set(value.clone());
+
+                if value.trim().is_empty() {
+                    suggestions.set(None);
+                    if !search_query.is_empty() {
+                        search_query.set(String::new());
+                        page.set(1);
+                    }
+                    return;
+                }
+
+                let suggestions = suggestions.clone();
+                spawn_local(async move {
+                    let url = format!(
+                        "/api/projects/suggest?q={}",
+                        String::from(js_sys::encode_uri_component(&value))
+                    );
+                    if let Ok(response) = Request::get(&url).send().await {
+                        if let Ok(data) = response.json::<Value>().await {
+                            if let Some(response_data) = data.get("data") {
+                                if let Ok(resp) = serde_json::from_value::<SuggestResponse>(response_data.clone()) {
+                                    suggestions.set(Some(resp));
+                                }
+                            }
+                        }
+                    }
+                });
             }
         })
     };
 
+    let on_search_submit = {
+        let search_input = search_input.clone();
+        let search_query = search_query.clone();
+        let suggestions = suggestions.clone();
+        let page = page.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            search_query.set(search_input.trim().to_string());
+            suggestions.set(None);
+            page.set(1); // Reset to first page on search
+        })
+    };
+
     // Category toggle
     let toggle_category = {
         let selected_categories = selected_categories.clone();
@@ -161,6 +222,52 @@ pub fn projects_filtered() -> Html {
         }
     };
 
+    // Picking a suggestion searches for it, or selects the country or program filter
+    let pick_suggestion = {
+        let search_input = search_input.clone();
+        let search_query = search_query.clone();
+        let suggestions = suggestions.clone();
+        let page = page.clone();
+        let toggle_country = toggle_country.clone();
+        let toggle_program = toggle_program.clone();
+        move |kind: &'static str, value: String| {
+            match kind {
+                "country" => toggle_country(value),
+                "program" => toggle_program(value),
+                _ => {
+                    search_input.set(value.clone());
+                    search_query.set(value);
+                    page.set(1);
+                }
+            }
+            suggestions.set(None);
+        }
+    };
+
+    let suggestion_group = |label: &'static str, kind: &'static str, items: &[Suggestion]| -> Html {
+        if items.is_empty() {
+            return html! {};
+        }
+        html! {
+            <div class="suggestion_group">
+                <span class="filter_label">{label}</span>
+                {for items.iter().map(|item| {
+                    let on_click = {
+                        let pick = pick_suggestion.clone();
+                        let value = item.value.clone();
+                        Callback::from(move |_| pick(kind, value.clone()))
+                    };
+                    html! {
+                        <button type="button" class="suggestion_item" onclick={on_click}>
+                            {&item.value}
+                            <span class="suggestion_count">{format!(" ({})", item.project_count)}</span>
+                        </button>
+                    }
+                })}
+            </div>
+        }
+    };
+
     // Pagination
     let next_page = {
         let page = page.clone();
@@ -183,6 +290,16 @@ pub fn projects_filtered() -> Html {
         })
     };
 
+    let facets = (*projects_data).as_ref().and_then(|data| data.facets.clone());
+    let tier_label = |value: &str, label: &str| -> String {
+        let count = facets
+            .as_ref()
+            .and_then(|f| f.funding_tiers.iter().find(|t| t.value == value))
+            .map(|t| format!(" ({})", t.count));
+        format!("{}{}", label, count.unwrap_or_default())
+    };
+    let yes_count = |count: Option<usize>| count.map(|c| format!(" ({})", c)).unwrap_or_default();
+
     html! {
         <Layout>
             <div class="projects_filtered_page">
@@ -194,15 +311,24 @@ pub fn projects_filtered() -> Html {
                 // Filter Panel
                 <div class="filter_container">
                     // Search Bar
-                    <div class="search_bar">
+                    <form class="search_bar" onsubmit={on_search_submit}>
                         <input
                             type="text"
                             class="search_input"
                             placeholder="SEARCH BY PROJECT NAME, COMPANY, OR DESCRIPTION..."
-                            value={(*search_query).clone()}
+                            value={(*search_input).clone()}
                             oninput={on_search}
                         />
-                    </div>
+                        if let Some(suggest) = &*suggestions {
+                            <div class="suggestions">
+                                {suggestion_group("PROJECTS", "project", &suggest.projects)}
+                                {suggestion_group("COMPANIES", "company", &suggest.companies)}
+                                {suggestion_group("TAGS", "tag", &suggest.tags)}
+                                {suggestion_group("COUNTRIES", "country", &suggest.countries)}
+                                {suggestion_group("PROGRAMS", "program", &suggest.programs)}
+                            </div>
+                        }
+                    </form>
 
                     // Quick Filters
                     <div class="quick_filters">
@@ -223,10 +349,10 @@ pub fn projects_filtered() -> Html {
                                 }}
                             >
                                 <option value="all">{"ALL TIERS"}</option>
-                                <option value="0-50k">{"$0 - $50K"}</option>
-                                <option value="50k-100k">{"$50K - $100K"}</option>
-                                <option value="100k-150k">{"$100K - $150K"}</option>
-                                <option value="150k+">{"$150K+"}</option>
+                                <option value="0-50k">{tier_label("0-50k", "$0 - $50K")}</option>
+                                <option value="50k-100k">{tier_label("50k-100k", "$50K - $100K")}</option>
+                                <option value="100k-150k">{tier_label("100k-150k", "$100K - $150K")}</option>
+                                <option value="150k+">{tier_label("150k+", "$150K+")}</option>
                             </select>
                         </div>
 
@@ -244,10 +370,15 @@ pub fn projects_filtered() -> Html {
                                     })
                                 }}
                             >
-                                <option value="funding_desc">{"FUNDING: HIGH TO LOW"}</option>
-                                <option value="funding_asc">{"FUNDING: LOW TO HIGH"}</option>
-                                <option value="name_asc">{"NAME: A TO Z"}</option>
-                                <option value="name_desc">{"NAME: Z TO A"}</option>
+                                <option value="funding:desc">{"FUNDING: HIGH TO LOW"}</option>
+                                <option value="funding:asc">{"FUNDING: LOW TO HIGH"}</option>
+                                <option value="title:asc">{"NAME: A TO Z"}</option>
+                                <option value="title:desc">{"NAME: Z TO A"}</option>
+                                <option value="quarter:desc,funding:desc">{"MOST RECENT AWARD"}</option>
+                                <option value="rounds:desc,funding:desc">{"MOST ROUNDS"}</option>
+                                <option value="country:asc,funding:desc">{"COUNTRY: A TO Z"}</option>
+                                <option value="social:desc,funding:desc">{"SOCIAL SCORE"}</option>
+                                <option value="health:desc,funding:desc">{"PROJECT HEALTH"}</option>
                             </select>
                         </div>
 
@@ -265,21 +396,57 @@ pub fn projects_filtered() -> Html {
                     // Advanced Filters (collapsible)
                     if *show_advanced {
                         <div class="advanced_filters">
+                            // Query language
+                            <div class="filter_section">
+                                <h3 class="section_title">{"QUERY"}</h3>
+                                <form onsubmit={{
+                                    let query_input = query_input.clone();
+                                    let query = query.clone();
+                                    let page = page.clone();
+                                    Callback::from(move |e: SubmitEvent| {
+                                        e.prevent_default();
+                                        query.set((*query_input).clone());
+                                        page.set(1);
+                                    })
+                                }}>
+                                    <input
+                                        type="text"
+                                        class="search_input"
+                                        placeholder="category:defi funding>=100k soroban:true -chain:ethereum"
+                                        value={(*query_input).clone()}
+                                        oninput={{
+                                            let query_input = query_input.clone();
+                                            Callback::from(move |e: InputEvent| {
+                                                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                                                    query_input.set(input.value());
+                                                }
+                                            })
+                                        }}
+                                    />
+                                </form>
+                                if let Some(error) = &*query_error {
+                                    <p class="query_error">{error}</p>
+                                }
+                            </div>
+
                             // Categories
                             <div class="filter_section">
                                 <h3 class="section_title">{"CATEGORIES"}</h3>
                                 <div class="checkbox_grid">
-                                    {for vec![
-                                        "Financial Protocols",
-                                        "Infrastructure & Services",
-                                        "Applications",
-                                        "Developer Tooling",
-                                        "Payments",
-                                        "DeFi",
-                                        "NFT & Collectibles",
-                                        "Data & Analytics"
-                                    ].iter().map(|cat| {
-                                        let category = cat.to_string();
+                                    {for facet_options(
+                                        facets.as_ref().map(|f| &f.categories),
+                                        &[
+                                            "Financial Protocols",
+                                            "Infrastructure & Services",
+                                            "Applications",
+                                            "Developer Tooling",
+                                            "Payments",
+                                            "DeFi",
+                                            "NFT & Collectibles",
+                                            "Data & Analytics"
+                                        ],
+                                        &selected_categories,
+                                    ).into_iter().map(|(category, label)| {
                                         let is_selected = (*selected_categories).contains(&category);
                                         let on_click = {
                                             let cat = category.clone();
@@ -293,7 +460,7 @@ pub fn projects_filtered() -> Html {
                                                     checked={is_selected}
                                                     onchange={on_click}
                                                 />
-                                                <span>{category}</span>
+                                                <span>{label}</span>
                                             </label>
                                         }
                                     })}
@@ -317,7 +484,7 @@ pub fn projects_filtered() -> Html {
                                                 })
                                             }}
                                         />
-                                        <span>{"SOROBAN PROJECTS"}</span>
+                                        <span>{format!("SOROBAN PROJECTS{}", yes_count(facets.as_ref().map(|f| f.soroban.yes)))}</span>
                                     </label>
                                     <label class={classes!("filter_checkbox", (*stellar_only).then(|| "active"))}>
                                         <input
@@ -347,7 +514,7 @@ pub fn projects_filtered() -> Html {
                                                 })
                                             }}
                                         />
-                                        <span>{"MAINNET LIVE"}</span>
+                                        <span>{format!("MAINNET LIVE{}", yes_count(facets.as_ref().map(|f| f.mainnet.yes)))}</span>
                                     </label>
                                     <label class={classes!("filter_checkbox", (*has_github).then(|| "active"))}>
                                         <input
@@ -371,9 +538,11 @@ pub fn projects_filtered() -> Html {
                             <div class="filter_section">
                                 <h3 class="section_title">{"GEOGRAPHIC"}</h3>
                                 <div class="checkbox_grid">
-                                    {for vec!["United States", "Portugal", "Germany", "United Kingdom", "Netherlands", "Spain", "France", "Singapore", "Chile", "Colombia"]
-                                        .iter().map(|country| {
-                                        let country_name = country.to_string();
+                                    {for facet_options(
+                                        facets.as_ref().map(|f| &f.countries),
+                                        &["United States", "Portugal", "Germany", "United Kingdom", "Netherlands", "Spain", "France", "Singapore", "Chile", "Colombia"],
+                                        &selected_countries,
+                                    ).into_iter().map(|(country_name, label)| {
                                         let is_selected = (*selected_countries).contains(&country_name);
                                         let on_click = {
                                             let c = country_name.clone();
@@ -387,7 +556,7 @@ pub fn projects_filtered() -> Html {
                                                     checked={is_selected}
                                                     onchange={on_click}
                                                 />
-                                                <span>{country_name}</span>
+                                                <span>{label}</span>
                                             </label>
                                         }
                                     })}
@@ -398,9 +567,11 @@ pub fn projects_filtered() -> Html {
                             <div class="filter_section">
                                 <h3 class="section_title">{"PROGRAMS"}</h3>
                                 <div class="checkbox_grid">
-                                    {for vec!["Kickstart", "Build", "Growth Hack", "Audit Bank"]
-                                        .iter().map(|prog| {
-                                        let program = prog.to_string();
+                                    {for facet_options(
+                                        facets.as_ref().map(|f| &f.programs),
+                                        &["Kickstart", "Build", "Growth Hack", "Audit Bank"],
+                                        &selected_programs,
+                                    ).into_iter().map(|(program, label)| {
                                         let is_selected = (*selected_programs).contains(&program);
                                         let on_click = {
                                             let p = program.clone();
@@ -414,7 +585,7 @@ pub fn projects_filtered() -> Html {
                                                     checked={is_selected}
                                                     onchange={on_click}
                                                 />
-                                                <span>{program}</span>
+                                                <span>{label}</span>
                                             </label>
                                         }
                                     })}
@@ -559,12 +730,35 @@ pub fn projects_filtered() -> Html {
     }
 }
 
+/// Options and labels for a checkbox facet: the server's counts once a page has loaded,
+/// otherwise the fallback list. Selected values stay listed even when nothing matches them.
+fn facet_options(facet: Option<&Vec<FacetCount>>, fallback: &[&str], selected: &[String]) -> Vec<(String, String)> {
+    let mut options: Vec<(String, String)> = match facet {
+        Some(counts) => counts
+            .iter()
+            .map(|c| (c.value.clone(), format!("{} ({})", c.value, c.count)))
+            .collect(),
+        None => fallback.iter().map(|v| (v.to_string(), v.to_string())).collect(),
+    };
+    for value in selected {
+        if !options.iter().any(|(option, _)| option == value) {
+            let label = if facet.is_some() { format!("{} (0)", value) } else { value.clone() };
+            options.push((value.clone(), label));
+        }
+    }
+    options
+}
+
 fn build_query_params(filter: &ProjectFilter) -> String {
-    let mut params = Vec::new();
+    // Cards only need the summary fields
+    let mut params = vec!["fields=summary".to_string()];
 
     if let Some(ref query) = filter.search_query {
         params.push(format!("search_query={}", query.replace(" ", "%20")));
     }
+    if let Some(ref q) = filter.q {
+        params.push(format!("q={}", String::from(js_sys::encode_uri_component(q))));
+    }
     if let Some(ref cats) = filter.categories {
         for cat in cats {
             params.push(format!("categories={}", cat.replace(" ", "%20").replace("&", "%26")));
@@ -598,8 +792,8 @@ fn build_query_params(filter: &ProjectFilter) -> String {
     if let Some(rounds) = filter.min_rounds {
         params.push(format!("min_rounds={}", rounds));
     }
-    if let Some(ref sort) = filter.sort_by {
-        params.push(format!("sort_by={}", sort));
+    if let Some(ref sort) = filter.sort {
+        params.push(format!("sort={}", sort));
     }
     if let Some(page) = filter.page {
         params.push(format!("page={}", page));
@@ -13,9 +13,23 @@ pub struct CategoryBreakdownResponse {
     pub categories: Vec<CategoryStats>,
 }
 
+/// `granularity=quarter|year` for `/api/analytics/timeline`, which also takes the
+/// `/api/projects` filters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimelineQuery {
+    pub granularity: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineResponse {
+    /// "quarter" or "year"
+    pub granularity: String,
+    /// Every period from the first award to the last, empty ones included
     pub timeline: Vec<TimelineData>,
+    /// Largest total funding first
+    pub categories: Vec<CategoryTimeline>,
+    /// Matching projects without a recognizable award quarter, left out of the timeline
+    pub undated_projects: usize,
 }
 
 // Feature 1: Opportunity Heatmap
@@ -100,8 +114,11 @@ pub struct CategoryDeepDiveResponse {
 // Feature 7: Gap Analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GapAnalysisResponse {
+    /// Every occupied category × region × technology cell, best score first
     pub opportunities: Vec<GapOpportunity>,
+    /// Cells, occupied or not, scoring above 0.5: denser funding or less competition than typical
     pub total_gaps: usize,
+    /// The best-scoring empty cells among categories, regions and technologies that have projects
     pub zero_competition: Vec<GapOpportunity>,
 }
 
@@ -109,6 +126,8 @@ pub struct GapAnalysisResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuccessPatternRequest {
     pub category: String,
+    /// Trait keys such as `soroban`, `open_source`, `github` or `docs`
+    #[serde(default)]
     pub your_traits: Vec<String>,
 }
 
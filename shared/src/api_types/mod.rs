@@ -2,8 +2,10 @@ mod auth;
 mod projects;
 mod analytics;
 mod predictor;
+mod saved_searches;
 
 pub use auth::*;
 pub use projects::*;
 pub use analytics::*;
 pub use predictor::*;
+pub use saved_searches::*;
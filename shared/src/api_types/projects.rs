@@ -1,13 +1,92 @@
 use serde::{Deserialize, Serialize};
-use crate::models::Project;
+use crate::models::{Project, ProjectEnrichment, SearchMatch};
 
+/// A page of projects; with `fields=` the projects carry only the requested fields, and
+/// with `fields=summary` they deserialize as `ProjectSummary`
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProjectsResponse {
-    pub projects: Vec<Project>,
+pub struct ProjectsResponse<P = Project> {
+    pub projects: Vec<P>,
     pub total: usize,
     pub page: usize,
     pub per_page: usize,
     pub total_pages: usize,
+    /// Pass back as `cursor` for the following page; absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Pass back as `cursor` for the preceding page; absent on the first page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_cursor: Option<String>,
+    /// Option counts for the filter sidebar, on filtered listings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<ProjectFacets>,
+}
+
+/// A project as `/api/projects/{id}` and `POST /api/projects/search` return it: the stored
+/// fields, plus what the request attached
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectResponse {
+    #[serde(flatten)]
+    pub project: Project,
+    /// Enrichment requested with `include=`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enrichment: Option<ProjectEnrichment>,
+    /// Relevance of this project to the request's search query
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_match: Option<SearchMatch>,
+}
+
+/// What a project card shows, as returned for `fields=summary`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub id: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub project_type: Option<String>,
+    pub country: Option<String>,
+    pub category: Option<String>,
+    pub total_awarded: Option<f64>,
+    pub integration_status: Option<String>,
+    pub soroban: Option<bool>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Start of the description, cut at a word and ending in "…" when shortened
+    pub excerpt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_match: Option<SearchMatch>,
+}
+
+impl ProjectSummary {
+    pub fn uses_soroban(&self) -> bool {
+        self.soroban.unwrap_or(false)
+    }
+}
+
+/// Counts per filter option over the filtered projects. Each facet ignores its own
+/// selection, so picking "DeFi" still shows how many projects the other categories have.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectFacets {
+    pub categories: Vec<FacetCount>,
+    pub countries: Vec<FacetCount>,
+    pub programs: Vec<FacetCount>,
+    /// Chronological
+    pub quarters: Vec<FacetCount>,
+    /// Every tier in `funding_tier` order, including empty ones
+    pub funding_tiers: Vec<FacetCount>,
+    pub soroban: BooleanFacet,
+    pub mainnet: BooleanFacet,
+}
+
+/// A filter value as accepted by `ProjectFilter`, and how many projects it would match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BooleanFacet {
+    pub yes: usize,
+    pub no: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,10 +94,14 @@ pub struct ProjectSearchRequest {
     pub query: String,
     pub category: Option<String>,
     pub limit: Option<usize>,
+    /// Narrows the results exactly as the same filter does on `/api/projects`
+    #[serde(default)]
+    pub filter: Option<crate::models::ProjectFilter>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FavoriteProjectRequest {
+    pub project_id: String,
     pub project_title: String,
     pub project_data: serde_json::Value,
     pub notes: Option<String>,
@@ -29,3 +112,76 @@ pub struct FavoriteProjectResponse {
     pub id: String,
     pub message: String,
 }
+
+/// `include=social,website,team,regions` and `fields=title,category,...` on project list
+/// and detail requests
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IncludeQuery {
+    pub include: Option<String>,
+    pub fields: Option<String>,
+}
+
+/// `q` and an optional per-kind `limit` for `/api/projects/suggest`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuggestQuery {
+    #[serde(default)]
+    pub q: String,
+    pub limit: Option<usize>,
+}
+
+/// Autocomplete matches grouped by what they complete to
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuggestResponse {
+    pub query: String,
+    pub projects: Vec<Suggestion>,
+    pub companies: Vec<Suggestion>,
+    pub tags: Vec<Suggestion>,
+    pub countries: Vec<Suggestion>,
+    pub programs: Vec<Suggestion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub value: String,
+    /// Set on project suggestions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    pub project_count: usize,
+    pub total_funding: f64,
+    /// Typos between the query and the suggestion; 0 for a plain prefix match
+    pub distance: usize,
+}
+
+/// Optional `limit` for `/api/projects/{id}/similar`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SimilarQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarProjectsResponse {
+    pub project_id: String,
+    /// Most similar first
+    pub similar: Vec<SimilarProject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarProject {
+    pub project: Project,
+    /// Weighted blend of the breakdown, 0 to 1
+    pub score: f64,
+    pub breakdown: SimilarityBreakdown,
+}
+
+/// Similarity per dimension, each 0 to 1
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SimilarityBreakdown {
+    /// TF-IDF cosine over description and tags
+    pub text: f64,
+    pub category: f64,
+    pub project_type: f64,
+    /// Overlap of the other chains; 0 when neither project lists any
+    pub chains: f64,
+    pub soroban: f64,
+    pub country: f64,
+}
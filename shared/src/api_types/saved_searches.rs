@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use crate::api_types::ProjectsResponse;
+use crate::models::{Project, ProjectFilter, SavedSearch};
+
+/// Either `filter`, or `query` as a `/api/projects` query string such as
+/// `categories=DeFi&soroban_only=true&q=region:africa`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSavedSearchRequest {
+    pub name: String,
+    pub filter: Option<ProjectFilter>,
+    pub query: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameSavedSearchRequest {
+    pub name: String,
+}
+
+/// Paging for the results of a run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunSavedSearchQuery {
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearchRunResponse<P = Project> {
+    /// With the last-run fields updated when this run was recorded (a first page on the
+    /// current dataset)
+    pub search: SavedSearch,
+    pub results: ProjectsResponse<P>,
+    pub delta: SavedSearchDelta,
+}
+
+/// How the results changed since the previous run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedSearchDelta {
+    /// Unset on the first run, when everything counts as added
+    pub previous_count: Option<usize>,
+    pub count_change: i64,
+    /// Ids of projects matching now but not at the previous run
+    pub added: Vec<String>,
+    /// Ids of projects matching at the previous run but not now
+    pub removed: Vec<String>,
+}
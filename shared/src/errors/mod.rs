@@ -1,3 +1,5 @@
 mod api_error;
+mod query_error;
 
 pub use api_error::*;
+pub use query_error::*;
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A query that failed to parse, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryError {
+    pub message: String,
+    /// Character offset into the query where the problem starts
+    pub position: usize,
+    /// Characters covered by the problem, at least 1
+    pub length: usize,
+}
+
+impl QueryError {
+    pub fn new(message: impl Into<String>, position: usize, length: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+            length: length.max(1),
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
@@ -20,11 +20,31 @@ pub struct CategoryStats {
     pub percentage_of_total: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Awards in one quarter or year. A project awarded in several quarters counts in each,
+/// with its funding split evenly between them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimelineData {
+    /// "Q2 '23" or "2023"
     pub period: String,
+    /// Projects awarded in the period
     pub project_count: usize,
     pub total_funding: f64,
+    /// Projects whose first award falls in the period
+    #[serde(default)]
+    pub new_projects: usize,
+    /// Projects awarded up to and including the period
+    #[serde(default)]
+    pub cumulative_projects: usize,
+    #[serde(default)]
+    pub cumulative_funding: f64,
+}
+
+/// One category's timeline, over the same periods as the overall one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryTimeline {
+    pub category: String,
+    pub total_funding: f64,
+    pub timeline: Vec<TimelineData>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,8 +200,12 @@ pub struct FundingBucket {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopProject {
+    #[serde(default)]
+    pub id: String,
     pub title: String,
     pub funding: f64,
+    /// Traits the project has that fewer than half of its category share, and its funding
+    /// against the category median
     pub characteristics: Vec<String>,
 }
 
@@ -190,12 +214,15 @@ pub struct SuccessPatterns {
     pub soroban_percentage: f64,
     pub mainnet_percentage: f64,
     pub avg_rounds: f64,
+    /// Traits at least half of the top-funded quarter share, with how common they are
+    /// there and across the category
     pub common_traits: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GrowthTrend {
     pub trend: String, // "growing", "stable", "declining"
+    /// Change in funding over the dataset's last four quarters against the four before, in percent
     pub yoy_change: f64,
     pub recent_activity: String,
 }
@@ -204,12 +231,25 @@ pub struct GrowthTrend {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GapOpportunity {
     pub category: String,
+    /// Continent of the projects' country, or "Unknown region"
+    #[serde(default)]
+    pub region: String,
+    /// "Soroban", "Soroban + multichain", "Multichain" or "Stellar classic"
+    #[serde(default)]
+    pub technology: String,
     pub project_count: usize,
+    /// Over the cell's projects with a known award; 0 for an empty cell
     pub avg_funding: f64,
+    #[serde(default)]
+    pub median_funding: f64,
     pub max_funding: f64,
+    /// 0 to 1: the mean of funding density, `avg / (avg + overall avg)`, and openness,
+    /// `typical / (typical + count)` where typical is the mean projects per occupied cell
     pub opportunity_score: f64,
+    /// The numbers behind the score
     pub market_need: String,
     pub strategy: String,
+    /// Smallest to largest award in the cell, or in the category for an empty cell
     pub estimated_funding_range: FundingRange,
 }
 
@@ -217,19 +257,46 @@ pub struct GapOpportunity {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuccessAnalysis {
     pub category: String,
+    /// Every analysed trait, highest lift first
     pub common_traits: Vec<TraitAnalysis>,
+    /// Smallest to largest award among the category's top-decile projects
     pub optimal_funding_range: FundingRange,
+    /// Correlation between using Soroban and funding across the category, -1 to 1
     pub soroban_correlation: f64,
+    /// Correlation between being on mainnet and funding across the category, -1 to 1
     pub mainnet_correlation: f64,
-    pub success_probability: f64,
+    /// Share of the traits at least half of the top decile has that `your_traits` covers, in
+    /// percent; how closely the request matches the top decile, not a chance of success
+    pub top_decile_trait_coverage: f64,
     pub recommendations: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TraitAnalysis {
     pub trait_name: String,
+    /// Key accepted in `your_traits`
+    #[serde(default)]
+    pub trait_key: String,
+    /// Share of the category's projects with the trait, in percent
     pub occurrence_percentage: f64,
     pub avg_funding_with_trait: f64,
+    #[serde(default)]
+    pub avg_funding_without_trait: f64,
+    /// `avg_funding_with_trait / avg_funding_without_trait`; None when either side has no funded projects
+    #[serde(default)]
+    pub lift: Option<f64>,
+    /// Category projects with the trait, the group `avg_funding_with_trait` averages
+    #[serde(default)]
+    pub sample_size: usize,
+    /// Category projects without the trait, the group `avg_funding_without_trait` averages
+    #[serde(default)]
+    pub sample_size_without: usize,
+    /// Share of the category's top-decile projects with the trait, in percent
+    #[serde(default)]
+    pub top_decile_percentage: f64,
+    /// Whether the request's `your_traits` includes it
+    #[serde(default)]
+    pub you_have: bool,
     pub description: String,
 }
 
@@ -243,11 +310,15 @@ pub struct LiveDashboard {
     pub quarterly_stats: QuarterlyStats,
 }
 
+/// A category's awards in the current quarter against the one before
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrendingCategory {
     pub category: String,
+    /// Quarter-over-quarter change in funding, in percent; 100 when the previous quarter had none
     pub growth_rate: f64,
+    /// Funding in the current quarter
     pub recent_funding: f64,
+    /// Projects awarded in the current quarter
     pub project_velocity: f64,
 }
 
@@ -266,12 +337,18 @@ pub struct HotOpportunity {
     pub potential_funding: f64,
 }
 
+/// The latest quarter with awards in the data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuarterlyStats {
     pub current_quarter: String,
     pub funding_this_quarter: f64,
     pub projects_this_quarter: usize,
+    /// Funding this quarter as a percentage of the best quarter's
     pub comparison_to_best: f64,
+    #[serde(default)]
+    pub best_quarter: String,
+    #[serde(default)]
+    pub best_quarter_funding: f64,
 }
 
 // Feature 10: Proposal Template
@@ -293,3 +370,55 @@ pub struct TemplateSection {
     pub content: String,
     pub tips: Vec<String>,
 }
+
+// Geographic analytics, aggregated over ISO-normalized countries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountryRankings {
+    pub countries: Vec<CountryRanking>,
+    pub total_countries: usize,
+    /// Projects without a country, or with one outside the ISO table
+    pub unresolved_projects: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountryRanking {
+    pub country: String,
+    /// ISO 3166-1 alpha-2 code
+    pub code: String,
+    pub continent: String,
+    pub subregion: String,
+    pub project_count: usize,
+    pub total_funding: f64,
+    pub avg_funding: f64,
+    pub mainnet_rate: f64,
+    pub soroban_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionalAnalysis {
+    pub continents: Vec<RegionStats>,
+    /// Subregions with at least one project
+    pub regions: Vec<RegionStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionStats {
+    pub region: String,
+    /// Two-letter continent code or UN M49 subregion code
+    pub code: String,
+    pub continent: String,
+    pub project_count: usize,
+    pub country_count: usize,
+    pub total_funding: f64,
+    pub avg_funding: f64,
+    pub mainnet_rate: f64,
+    pub soroban_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeographicGaps {
+    pub funded_regions: Vec<String>,
+    /// Subregions without a single funded project
+    pub underserved_regions: Vec<String>,
+    pub expansion_recommendations: String,
+}
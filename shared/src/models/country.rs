@@ -0,0 +1,679 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Continents, identified by their conventional two-letter codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl Continent {
+    pub const ALL: [Continent; 7] = [
+        Self::Africa,
+        Self::Antarctica,
+        Self::Asia,
+        Self::Europe,
+        Self::NorthAmerica,
+        Self::Oceania,
+        Self::SouthAmerica,
+    ];
+
+    /// Accepts the name, the two-letter code or the UN M49 code
+    pub fn parse(s: &str) -> Option<Self> {
+        let key = lookup_key(s);
+        Self::ALL
+            .into_iter()
+            .find(|c| key == lookup_key(c.as_str()) || key == lookup_key(c.code()) || key == c.m49())
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Africa => "AF",
+            Self::Antarctica => "AN",
+            Self::Asia => "AS",
+            Self::Europe => "EU",
+            Self::NorthAmerica => "NA",
+            Self::Oceania => "OC",
+            Self::SouthAmerica => "SA",
+        }
+    }
+
+    pub fn m49(&self) -> &'static str {
+        match self {
+            Self::Africa => "002",
+            Self::Antarctica => "010",
+            Self::Asia => "142",
+            Self::Europe => "150",
+            Self::NorthAmerica => "003",
+            Self::Oceania => "009",
+            Self::SouthAmerica => "005",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Africa => "Africa",
+            Self::Antarctica => "Antarctica",
+            Self::Asia => "Asia",
+            Self::Europe => "Europe",
+            Self::NorthAmerica => "North America",
+            Self::Oceania => "Oceania",
+            Self::SouthAmerica => "South America",
+        }
+    }
+}
+
+/// UN M49 subregions, split so that each belongs to exactly one continent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Subregion {
+    NorthernAfrica,
+    EasternAfrica,
+    MiddleAfrica,
+    SouthernAfrica,
+    WesternAfrica,
+    Antarctica,
+    CentralAsia,
+    EasternAsia,
+    SouthEasternAsia,
+    SouthernAsia,
+    WesternAsia,
+    EasternEurope,
+    NorthernEurope,
+    SouthernEurope,
+    WesternEurope,
+    Caribbean,
+    CentralAmerica,
+    NorthernAmerica,
+    AustraliaAndNewZealand,
+    Melanesia,
+    Micronesia,
+    Polynesia,
+    SouthAmerica,
+}
+
+impl Subregion {
+    pub const ALL: [Subregion; 23] = [
+        Self::NorthernAfrica,
+        Self::EasternAfrica,
+        Self::MiddleAfrica,
+        Self::SouthernAfrica,
+        Self::WesternAfrica,
+        Self::Antarctica,
+        Self::CentralAsia,
+        Self::EasternAsia,
+        Self::SouthEasternAsia,
+        Self::SouthernAsia,
+        Self::WesternAsia,
+        Self::EasternEurope,
+        Self::NorthernEurope,
+        Self::SouthernEurope,
+        Self::WesternEurope,
+        Self::Caribbean,
+        Self::CentralAmerica,
+        Self::NorthernAmerica,
+        Self::AustraliaAndNewZealand,
+        Self::Melanesia,
+        Self::Micronesia,
+        Self::Polynesia,
+        Self::SouthAmerica,
+    ];
+
+    /// Accepts the name or the UN M49 code
+    pub fn parse(s: &str) -> Option<Self> {
+        let key = lookup_key(s);
+        Self::ALL
+            .into_iter()
+            .find(|r| key == lookup_key(r.as_str()) || key == r.m49())
+    }
+
+    pub fn continent(&self) -> Continent {
+        match self {
+            Self::NorthernAfrica
+            | Self::EasternAfrica
+            | Self::MiddleAfrica
+            | Self::SouthernAfrica
+            | Self::WesternAfrica => Continent::Africa,
+            Self::Antarctica => Continent::Antarctica,
+            Self::CentralAsia
+            | Self::EasternAsia
+            | Self::SouthEasternAsia
+            | Self::SouthernAsia
+            | Self::WesternAsia => Continent::Asia,
+            Self::EasternEurope | Self::NorthernEurope | Self::SouthernEurope | Self::WesternEurope => {
+                Continent::Europe
+            }
+            Self::Caribbean | Self::CentralAmerica | Self::NorthernAmerica => Continent::NorthAmerica,
+            Self::AustraliaAndNewZealand | Self::Melanesia | Self::Micronesia | Self::Polynesia => {
+                Continent::Oceania
+            }
+            Self::SouthAmerica => Continent::SouthAmerica,
+        }
+    }
+
+    pub fn m49(&self) -> &'static str {
+        match self {
+            Self::NorthernAfrica => "015",
+            Self::EasternAfrica => "014",
+            Self::MiddleAfrica => "017",
+            Self::SouthernAfrica => "018",
+            Self::WesternAfrica => "011",
+            Self::Antarctica => "010",
+            Self::CentralAsia => "143",
+            Self::EasternAsia => "030",
+            Self::SouthEasternAsia => "035",
+            Self::SouthernAsia => "034",
+            Self::WesternAsia => "145",
+            Self::EasternEurope => "151",
+            Self::NorthernEurope => "154",
+            Self::SouthernEurope => "039",
+            Self::WesternEurope => "155",
+            Self::Caribbean => "029",
+            Self::CentralAmerica => "013",
+            Self::NorthernAmerica => "021",
+            Self::AustraliaAndNewZealand => "053",
+            Self::Melanesia => "054",
+            Self::Micronesia => "057",
+            Self::Polynesia => "061",
+            Self::SouthAmerica => "005",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NorthernAfrica => "Northern Africa",
+            Self::EasternAfrica => "Eastern Africa",
+            Self::MiddleAfrica => "Middle Africa",
+            Self::SouthernAfrica => "Southern Africa",
+            Self::WesternAfrica => "Western Africa",
+            Self::Antarctica => "Antarctica",
+            Self::CentralAsia => "Central Asia",
+            Self::EasternAsia => "Eastern Asia",
+            Self::SouthEasternAsia => "South-eastern Asia",
+            Self::SouthernAsia => "Southern Asia",
+            Self::WesternAsia => "Western Asia",
+            Self::EasternEurope => "Eastern Europe",
+            Self::NorthernEurope => "Northern Europe",
+            Self::SouthernEurope => "Southern Europe",
+            Self::WesternEurope => "Western Europe",
+            Self::Caribbean => "Caribbean",
+            Self::CentralAmerica => "Central America",
+            Self::NorthernAmerica => "Northern America",
+            Self::AustraliaAndNewZealand => "Australia and New Zealand",
+            Self::Melanesia => "Melanesia",
+            Self::Micronesia => "Micronesia",
+            Self::Polynesia => "Polynesia",
+            Self::SouthAmerica => "South America",
+        }
+    }
+}
+
+/// A continent or subregion, as accepted by the `region`/`regions` filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    Continent(Continent),
+    Subregion(Subregion),
+}
+
+impl Region {
+    /// Continents first, so "South America" and "005" resolve to the continent
+    pub fn parse(s: &str) -> Option<Self> {
+        Continent::parse(s)
+            .map(Self::Continent)
+            .or_else(|| Subregion::parse(s).map(Self::Subregion))
+    }
+
+    pub fn contains(&self, country: &Country) -> bool {
+        match self {
+            Self::Continent(continent) => country.continent() == *continent,
+            Self::Subregion(subregion) => country.subregion == *subregion,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Continent(continent) => continent.as_str(),
+            Self::Subregion(subregion) => subregion.as_str(),
+        }
+    }
+}
+
+/// An ISO 3166-1 country with its place in the continent/subregion hierarchy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Country {
+    pub alpha2: &'static str,
+    pub alpha3: &'static str,
+    /// Common English short name, e.g. "United States" rather than the formal ISO name
+    pub name: &'static str,
+    pub subregion: Subregion,
+}
+
+impl Country {
+    /// Resolve a free-text country by ISO alpha-2 or alpha-3 code, name or known alias.
+    /// Case, spacing and punctuation are ignored, so "U.S.", "usa" and "United States" agree.
+    pub fn lookup(s: &str) -> Option<&'static Country> {
+        static INDEX: OnceLock<HashMap<String, &'static Country>> = OnceLock::new();
+
+        let index = INDEX.get_or_init(|| {
+            let mut index = HashMap::new();
+            for country in &COUNTRIES {
+                for spelling in [country.alpha2, country.alpha3, country.name] {
+                    index.insert(lookup_key(spelling), country);
+                }
+            }
+            for (alias, alpha2) in ALIASES {
+                if let Some(country) = COUNTRIES.iter().find(|c| c.alpha2 == *alpha2) {
+                    index.entry(lookup_key(alias)).or_insert(country);
+                }
+            }
+            index
+        });
+
+        index.get(&lookup_key(s)).copied()
+    }
+
+    pub fn all() -> &'static [Country] {
+        &COUNTRIES
+    }
+
+    pub fn continent(&self) -> Continent {
+        self.subregion.continent()
+    }
+
+    /// Every spelling `lookup` accepts for this country
+    pub fn spellings(&self) -> impl Iterator<Item = &'static str> + '_ {
+        [self.alpha2, self.alpha3, self.name].into_iter().chain(
+            ALIASES
+                .iter()
+                .filter(|(_, alpha2)| *alpha2 == self.alpha2)
+                .map(|(alias, _)| *alias),
+        )
+    }
+}
+
+/// Lowercased letters and digits only
+fn lookup_key(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+const fn country(
+    alpha2: &'static str,
+    alpha3: &'static str,
+    name: &'static str,
+    subregion: Subregion,
+) -> Country {
+    Country { alpha2, alpha3, name, subregion }
+}
+
+use Subregion::*;
+
+static COUNTRIES: [Country; 249] = [
+    country("AD", "AND", "Andorra", SouthernEurope),
+    country("AE", "ARE", "United Arab Emirates", WesternAsia),
+    country("AF", "AFG", "Afghanistan", SouthernAsia),
+    country("AG", "ATG", "Antigua and Barbuda", Caribbean),
+    country("AI", "AIA", "Anguilla", Caribbean),
+    country("AL", "ALB", "Albania", SouthernEurope),
+    country("AM", "ARM", "Armenia", WesternAsia),
+    country("AO", "AGO", "Angola", MiddleAfrica),
+    country("AQ", "ATA", "Antarctica", Antarctica),
+    country("AR", "ARG", "Argentina", SouthAmerica),
+    country("AS", "ASM", "American Samoa", Polynesia),
+    country("AT", "AUT", "Austria", WesternEurope),
+    country("AU", "AUS", "Australia", AustraliaAndNewZealand),
+    country("AW", "ABW", "Aruba", Caribbean),
+    country("AX", "ALA", "Åland Islands", NorthernEurope),
+    country("AZ", "AZE", "Azerbaijan", WesternAsia),
+    country("BA", "BIH", "Bosnia and Herzegovina", SouthernEurope),
+    country("BB", "BRB", "Barbados", Caribbean),
+    country("BD", "BGD", "Bangladesh", SouthernAsia),
+    country("BE", "BEL", "Belgium", WesternEurope),
+    country("BF", "BFA", "Burkina Faso", WesternAfrica),
+    country("BG", "BGR", "Bulgaria", EasternEurope),
+    country("BH", "BHR", "Bahrain", WesternAsia),
+    country("BI", "BDI", "Burundi", EasternAfrica),
+    country("BJ", "BEN", "Benin", WesternAfrica),
+    country("BL", "BLM", "Saint Barthélemy", Caribbean),
+    country("BM", "BMU", "Bermuda", NorthernAmerica),
+    country("BN", "BRN", "Brunei", SouthEasternAsia),
+    country("BO", "BOL", "Bolivia", SouthAmerica),
+    country("BQ", "BES", "Caribbean Netherlands", Caribbean),
+    country("BR", "BRA", "Brazil", SouthAmerica),
+    country("BS", "BHS", "Bahamas", Caribbean),
+    country("BT", "BTN", "Bhutan", SouthernAsia),
+    country("BV", "BVT", "Bouvet Island", SouthAmerica),
+    country("BW", "BWA", "Botswana", SouthernAfrica),
+    country("BY", "BLR", "Belarus", EasternEurope),
+    country("BZ", "BLZ", "Belize", CentralAmerica),
+    country("CA", "CAN", "Canada", NorthernAmerica),
+    country("CC", "CCK", "Cocos (Keeling) Islands", AustraliaAndNewZealand),
+    country("CD", "COD", "Democratic Republic of the Congo", MiddleAfrica),
+    country("CF", "CAF", "Central African Republic", MiddleAfrica),
+    country("CG", "COG", "Congo", MiddleAfrica),
+    country("CH", "CHE", "Switzerland", WesternEurope),
+    country("CI", "CIV", "Côte d'Ivoire", WesternAfrica),
+    country("CK", "COK", "Cook Islands", Polynesia),
+    country("CL", "CHL", "Chile", SouthAmerica),
+    country("CM", "CMR", "Cameroon", MiddleAfrica),
+    country("CN", "CHN", "China", EasternAsia),
+    country("CO", "COL", "Colombia", SouthAmerica),
+    country("CR", "CRI", "Costa Rica", CentralAmerica),
+    country("CU", "CUB", "Cuba", Caribbean),
+    country("CV", "CPV", "Cabo Verde", WesternAfrica),
+    country("CW", "CUW", "Curaçao", Caribbean),
+    country("CX", "CXR", "Christmas Island", AustraliaAndNewZealand),
+    country("CY", "CYP", "Cyprus", WesternAsia),
+    country("CZ", "CZE", "Czechia", EasternEurope),
+    country("DE", "DEU", "Germany", WesternEurope),
+    country("DJ", "DJI", "Djibouti", EasternAfrica),
+    country("DK", "DNK", "Denmark", NorthernEurope),
+    country("DM", "DMA", "Dominica", Caribbean),
+    country("DO", "DOM", "Dominican Republic", Caribbean),
+    country("DZ", "DZA", "Algeria", NorthernAfrica),
+    country("EC", "ECU", "Ecuador", SouthAmerica),
+    country("EE", "EST", "Estonia", NorthernEurope),
+    country("EG", "EGY", "Egypt", NorthernAfrica),
+    country("EH", "ESH", "Western Sahara", NorthernAfrica),
+    country("ER", "ERI", "Eritrea", EasternAfrica),
+    country("ES", "ESP", "Spain", SouthernEurope),
+    country("ET", "ETH", "Ethiopia", EasternAfrica),
+    country("FI", "FIN", "Finland", NorthernEurope),
+    country("FJ", "FJI", "Fiji", Melanesia),
+    country("FK", "FLK", "Falkland Islands", SouthAmerica),
+    country("FM", "FSM", "Micronesia", Micronesia),
+    country("FO", "FRO", "Faroe Islands", NorthernEurope),
+    country("FR", "FRA", "France", WesternEurope),
+    country("GA", "GAB", "Gabon", MiddleAfrica),
+    country("GB", "GBR", "United Kingdom", NorthernEurope),
+    country("GD", "GRD", "Grenada", Caribbean),
+    country("GE", "GEO", "Georgia", WesternAsia),
+    country("GF", "GUF", "French Guiana", SouthAmerica),
+    country("GG", "GGY", "Guernsey", NorthernEurope),
+    country("GH", "GHA", "Ghana", WesternAfrica),
+    country("GI", "GIB", "Gibraltar", SouthernEurope),
+    country("GL", "GRL", "Greenland", NorthernAmerica),
+    country("GM", "GMB", "Gambia", WesternAfrica),
+    country("GN", "GIN", "Guinea", WesternAfrica),
+    country("GP", "GLP", "Guadeloupe", Caribbean),
+    country("GQ", "GNQ", "Equatorial Guinea", MiddleAfrica),
+    country("GR", "GRC", "Greece", SouthernEurope),
+    country("GS", "SGS", "South Georgia and the South Sandwich Islands", SouthAmerica),
+    country("GT", "GTM", "Guatemala", CentralAmerica),
+    country("GU", "GUM", "Guam", Micronesia),
+    country("GW", "GNB", "Guinea-Bissau", WesternAfrica),
+    country("GY", "GUY", "Guyana", SouthAmerica),
+    country("HK", "HKG", "Hong Kong", EasternAsia),
+    country("HM", "HMD", "Heard Island and McDonald Islands", AustraliaAndNewZealand),
+    country("HN", "HND", "Honduras", CentralAmerica),
+    country("HR", "HRV", "Croatia", SouthernEurope),
+    country("HT", "HTI", "Haiti", Caribbean),
+    country("HU", "HUN", "Hungary", EasternEurope),
+    country("ID", "IDN", "Indonesia", SouthEasternAsia),
+    country("IE", "IRL", "Ireland", NorthernEurope),
+    country("IL", "ISR", "Israel", WesternAsia),
+    country("IM", "IMN", "Isle of Man", NorthernEurope),
+    country("IN", "IND", "India", SouthernAsia),
+    country("IO", "IOT", "British Indian Ocean Territory", EasternAfrica),
+    country("IQ", "IRQ", "Iraq", WesternAsia),
+    country("IR", "IRN", "Iran", SouthernAsia),
+    country("IS", "ISL", "Iceland", NorthernEurope),
+    country("IT", "ITA", "Italy", SouthernEurope),
+    country("JE", "JEY", "Jersey", NorthernEurope),
+    country("JM", "JAM", "Jamaica", Caribbean),
+    country("JO", "JOR", "Jordan", WesternAsia),
+    country("JP", "JPN", "Japan", EasternAsia),
+    country("KE", "KEN", "Kenya", EasternAfrica),
+    country("KG", "KGZ", "Kyrgyzstan", CentralAsia),
+    country("KH", "KHM", "Cambodia", SouthEasternAsia),
+    country("KI", "KIR", "Kiribati", Micronesia),
+    country("KM", "COM", "Comoros", EasternAfrica),
+    country("KN", "KNA", "Saint Kitts and Nevis", Caribbean),
+    country("KP", "PRK", "North Korea", EasternAsia),
+    country("KR", "KOR", "South Korea", EasternAsia),
+    country("KW", "KWT", "Kuwait", WesternAsia),
+    country("KY", "CYM", "Cayman Islands", Caribbean),
+    country("KZ", "KAZ", "Kazakhstan", CentralAsia),
+    country("LA", "LAO", "Laos", SouthEasternAsia),
+    country("LB", "LBN", "Lebanon", WesternAsia),
+    country("LC", "LCA", "Saint Lucia", Caribbean),
+    country("LI", "LIE", "Liechtenstein", WesternEurope),
+    country("LK", "LKA", "Sri Lanka", SouthernAsia),
+    country("LR", "LBR", "Liberia", WesternAfrica),
+    country("LS", "LSO", "Lesotho", SouthernAfrica),
+    country("LT", "LTU", "Lithuania", NorthernEurope),
+    country("LU", "LUX", "Luxembourg", WesternEurope),
+    country("LV", "LVA", "Latvia", NorthernEurope),
+    country("LY", "LBY", "Libya", NorthernAfrica),
+    country("MA", "MAR", "Morocco", NorthernAfrica),
+    country("MC", "MCO", "Monaco", WesternEurope),
+    country("MD", "MDA", "Moldova", EasternEurope),
+    country("ME", "MNE", "Montenegro", SouthernEurope),
+    country("MF", "MAF", "Saint Martin", Caribbean),
+    country("MG", "MDG", "Madagascar", EasternAfrica),
+    country("MH", "MHL", "Marshall Islands", Micronesia),
+    country("MK", "MKD", "North Macedonia", SouthernEurope),
+    country("ML", "MLI", "Mali", WesternAfrica),
+    country("MM", "MMR", "Myanmar", SouthEasternAsia),
+    country("MN", "MNG", "Mongolia", EasternAsia),
+    country("MO", "MAC", "Macao", EasternAsia),
+    country("MP", "MNP", "Northern Mariana Islands", Micronesia),
+    country("MQ", "MTQ", "Martinique", Caribbean),
+    country("MR", "MRT", "Mauritania", WesternAfrica),
+    country("MS", "MSR", "Montserrat", Caribbean),
+    country("MT", "MLT", "Malta", SouthernEurope),
+    country("MU", "MUS", "Mauritius", EasternAfrica),
+    country("MV", "MDV", "Maldives", SouthernAsia),
+    country("MW", "MWI", "Malawi", EasternAfrica),
+    country("MX", "MEX", "Mexico", CentralAmerica),
+    country("MY", "MYS", "Malaysia", SouthEasternAsia),
+    country("MZ", "MOZ", "Mozambique", EasternAfrica),
+    country("NA", "NAM", "Namibia", SouthernAfrica),
+    country("NC", "NCL", "New Caledonia", Melanesia),
+    country("NE", "NER", "Niger", WesternAfrica),
+    country("NF", "NFK", "Norfolk Island", AustraliaAndNewZealand),
+    country("NG", "NGA", "Nigeria", WesternAfrica),
+    country("NI", "NIC", "Nicaragua", CentralAmerica),
+    country("NL", "NLD", "Netherlands", WesternEurope),
+    country("NO", "NOR", "Norway", NorthernEurope),
+    country("NP", "NPL", "Nepal", SouthernAsia),
+    country("NR", "NRU", "Nauru", Micronesia),
+    country("NU", "NIU", "Niue", Polynesia),
+    country("NZ", "NZL", "New Zealand", AustraliaAndNewZealand),
+    country("OM", "OMN", "Oman", WesternAsia),
+    country("PA", "PAN", "Panama", CentralAmerica),
+    country("PE", "PER", "Peru", SouthAmerica),
+    country("PF", "PYF", "French Polynesia", Polynesia),
+    country("PG", "PNG", "Papua New Guinea", Melanesia),
+    country("PH", "PHL", "Philippines", SouthEasternAsia),
+    country("PK", "PAK", "Pakistan", SouthernAsia),
+    country("PL", "POL", "Poland", EasternEurope),
+    country("PM", "SPM", "Saint Pierre and Miquelon", NorthernAmerica),
+    country("PN", "PCN", "Pitcairn Islands", Polynesia),
+    country("PR", "PRI", "Puerto Rico", Caribbean),
+    country("PS", "PSE", "Palestine", WesternAsia),
+    country("PT", "PRT", "Portugal", SouthernEurope),
+    country("PW", "PLW", "Palau", Micronesia),
+    country("PY", "PRY", "Paraguay", SouthAmerica),
+    country("QA", "QAT", "Qatar", WesternAsia),
+    country("RE", "REU", "Réunion", EasternAfrica),
+    country("RO", "ROU", "Romania", EasternEurope),
+    country("RS", "SRB", "Serbia", SouthernEurope),
+    country("RU", "RUS", "Russia", EasternEurope),
+    country("RW", "RWA", "Rwanda", EasternAfrica),
+    country("SA", "SAU", "Saudi Arabia", WesternAsia),
+    country("SB", "SLB", "Solomon Islands", Melanesia),
+    country("SC", "SYC", "Seychelles", EasternAfrica),
+    country("SD", "SDN", "Sudan", NorthernAfrica),
+    country("SE", "SWE", "Sweden", NorthernEurope),
+    country("SG", "SGP", "Singapore", SouthEasternAsia),
+    country("SH", "SHN", "Saint Helena, Ascension and Tristan da Cunha", WesternAfrica),
+    country("SI", "SVN", "Slovenia", SouthernEurope),
+    country("SJ", "SJM", "Svalbard and Jan Mayen", NorthernEurope),
+    country("SK", "SVK", "Slovakia", EasternEurope),
+    country("SL", "SLE", "Sierra Leone", WesternAfrica),
+    country("SM", "SMR", "San Marino", SouthernEurope),
+    country("SN", "SEN", "Senegal", WesternAfrica),
+    country("SO", "SOM", "Somalia", EasternAfrica),
+    country("SR", "SUR", "Suriname", SouthAmerica),
+    country("SS", "SSD", "South Sudan", EasternAfrica),
+    country("ST", "STP", "São Tomé and Príncipe", MiddleAfrica),
+    country("SV", "SLV", "El Salvador", CentralAmerica),
+    country("SX", "SXM", "Sint Maarten", Caribbean),
+    country("SY", "SYR", "Syria", WesternAsia),
+    country("SZ", "SWZ", "Eswatini", SouthernAfrica),
+    country("TC", "TCA", "Turks and Caicos Islands", Caribbean),
+    country("TD", "TCD", "Chad", MiddleAfrica),
+    country("TF", "ATF", "French Southern Territories", EasternAfrica),
+    country("TG", "TGO", "Togo", WesternAfrica),
+    country("TH", "THA", "Thailand", SouthEasternAsia),
+    country("TJ", "TJK", "Tajikistan", CentralAsia),
+    country("TK", "TKL", "Tokelau", Polynesia),
+    country("TL", "TLS", "Timor-Leste", SouthEasternAsia),
+    country("TM", "TKM", "Turkmenistan", CentralAsia),
+    country("TN", "TUN", "Tunisia", NorthernAfrica),
+    country("TO", "TON", "Tonga", Polynesia),
+    country("TR", "TUR", "Turkey", WesternAsia),
+    country("TT", "TTO", "Trinidad and Tobago", Caribbean),
+    country("TV", "TUV", "Tuvalu", Polynesia),
+    country("TW", "TWN", "Taiwan", EasternAsia),
+    country("TZ", "TZA", "Tanzania", EasternAfrica),
+    country("UA", "UKR", "Ukraine", EasternEurope),
+    country("UG", "UGA", "Uganda", EasternAfrica),
+    country("UM", "UMI", "United States Minor Outlying Islands", Micronesia),
+    country("US", "USA", "United States", NorthernAmerica),
+    country("UY", "URY", "Uruguay", SouthAmerica),
+    country("UZ", "UZB", "Uzbekistan", CentralAsia),
+    country("VA", "VAT", "Vatican City", SouthernEurope),
+    country("VC", "VCT", "Saint Vincent and the Grenadines", Caribbean),
+    country("VE", "VEN", "Venezuela", SouthAmerica),
+    country("VG", "VGB", "British Virgin Islands", Caribbean),
+    country("VI", "VIR", "United States Virgin Islands", Caribbean),
+    country("VN", "VNM", "Vietnam", SouthEasternAsia),
+    country("VU", "VUT", "Vanuatu", Melanesia),
+    country("WF", "WLF", "Wallis and Futuna", Polynesia),
+    country("WS", "WSM", "Samoa", Polynesia),
+    country("YE", "YEM", "Yemen", WesternAsia),
+    country("YT", "MYT", "Mayotte", EasternAfrica),
+    country("ZA", "ZAF", "South Africa", SouthernAfrica),
+    country("ZM", "ZMB", "Zambia", EasternAfrica),
+    country("ZW", "ZWE", "Zimbabwe", EasternAfrica),
+];
+
+/// Other spellings seen in applications, mapped to the alpha-2 code
+static ALIASES: &[(&str, &str)] = &[
+    ("United States of America", "US"),
+    ("America", "US"),
+    ("UK", "GB"),
+    ("Great Britain", "GB"),
+    ("Britain", "GB"),
+    ("England", "GB"),
+    ("Scotland", "GB"),
+    ("Wales", "GB"),
+    ("Northern Ireland", "GB"),
+    ("United Kingdom of Great Britain and Northern Ireland", "GB"),
+    ("UAE", "AE"),
+    ("Emirates", "AE"),
+    ("Korea", "KR"),
+    ("Republic of Korea", "KR"),
+    ("Korea, Republic of", "KR"),
+    ("Democratic People's Republic of Korea", "KP"),
+    ("Russian Federation", "RU"),
+    ("Viet Nam", "VN"),
+    ("Ivory Coast", "CI"),
+    ("Cote d'Ivoire", "CI"),
+    ("Czech Republic", "CZ"),
+    ("Türkiye", "TR"),
+    ("Turkiye", "TR"),
+    ("Holland", "NL"),
+    ("The Netherlands", "NL"),
+    ("Lao People's Democratic Republic", "LA"),
+    ("Syrian Arab Republic", "SY"),
+    ("Iran, Islamic Republic of", "IR"),
+    ("Bolivia, Plurinational State of", "BO"),
+    ("Venezuela, Bolivarian Republic of", "VE"),
+    ("United Republic of Tanzania", "TZ"),
+    ("Republic of Moldova", "MD"),
+    ("Taiwan, Province of China", "TW"),
+    ("Hong Kong SAR", "HK"),
+    ("Macau", "MO"),
+    ("State of Palestine", "PS"),
+    ("Palestinian Territories", "PS"),
+    ("Holy See", "VA"),
+    ("Vatican", "VA"),
+    ("Burma", "MM"),
+    ("Swaziland", "SZ"),
+    ("Cape Verde", "CV"),
+    ("Macedonia", "MK"),
+    ("DRC", "CD"),
+    ("DR Congo", "CD"),
+    ("Congo-Kinshasa", "CD"),
+    ("Republic of the Congo", "CG"),
+    ("Congo-Brazzaville", "CG"),
+    ("Federated States of Micronesia", "FM"),
+    ("Brunei Darussalam", "BN"),
+    ("East Timor", "TL"),
+    ("The Bahamas", "BS"),
+    ("The Gambia", "GM"),
+    ("The Philippines", "PH"),
+    ("Sao Tome and Principe", "ST"),
+    ("Curacao", "CW"),
+    ("Reunion", "RE"),
+    ("Saint Barthelemy", "BL"),
+    ("Aland Islands", "AX"),
+    ("Saint Helena", "SH"),
+    ("US Virgin Islands", "VI"),
+    ("Bonaire", "BQ"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_country_lookup_accepts_codes_names_and_aliases() {
+        let us = Country::lookup("United States").unwrap();
+
+        for spelling in ["US", "usa", "U.S.", "U.S.A.", "United States of America", " united states "] {
+            assert_eq!(Country::lookup(spelling), Some(us), "{}", spelling);
+        }
+        assert_eq!(Country::lookup("UK").unwrap().alpha2, "GB");
+        assert_eq!(Country::lookup("Ivory Coast").unwrap().name, "Côte d'Ivoire");
+        assert_eq!(Country::lookup("Atlantis"), None);
+        assert_eq!(us.continent(), Continent::NorthAmerica);
+    }
+
+    #[test]
+    fn test_country_table_is_consistent() {
+        let mut codes = std::collections::HashSet::new();
+        for country in Country::all() {
+            assert!(codes.insert(country.alpha2), "duplicate {}", country.alpha2);
+            assert!(codes.insert(country.alpha3), "duplicate {}", country.alpha3);
+            assert_eq!(Country::lookup(country.name), Some(country));
+        }
+        for (alias, alpha2) in ALIASES {
+            assert_eq!(Country::lookup(alias).map(|c| c.alpha2), Some(*alpha2), "{}", alias);
+        }
+    }
+
+    #[test]
+    fn test_region_parse() {
+        assert_eq!(Region::parse("EU"), Some(Region::Continent(Continent::Europe)));
+        assert_eq!(Region::parse("africa"), Some(Region::Continent(Continent::Africa)));
+        assert_eq!(Region::parse("Western Africa"), Some(Region::Subregion(Subregion::WesternAfrica)));
+        assert_eq!(Region::parse("155"), Some(Region::Subregion(Subregion::WesternEurope)));
+        assert_eq!(Region::parse("LATAM"), None);
+
+        let kenya = Country::lookup("KE").unwrap();
+        assert!(Region::parse("AF").unwrap().contains(kenya));
+        assert!(Region::parse("Eastern Africa").unwrap().contains(kenya));
+        assert!(!Region::parse("Europe").unwrap().contains(kenya));
+    }
+}
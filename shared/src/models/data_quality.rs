@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// Result of auditing a loaded project dataset for data problems
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataQualityReport {
+    pub snapshot_id: String,
+    pub generated_at: DateTime<Utc>,
+    pub project_count: usize,
+    /// Total number of findings across every section below
+    pub issue_count: usize,
+    pub missing_fields: Vec<FieldCompleteness>,
+    pub duplicate_titles: Vec<DuplicateGroup>,
+    pub duplicate_companies: Vec<DuplicateGroup>,
+    pub country_spellings: Vec<SpellingGroup>,
+    pub funding_outliers: Vec<FundingOutlier>,
+    pub unparseable_quarters: Vec<UnparseableValue>,
+    pub missing_enrichment: Vec<MissingEnrichment>,
+    /// Enrichment sources with no records at all, left out of `missing_enrichment`
+    pub empty_enrichment_sources: Vec<String>,
+}
+
+/// How many projects leave a column empty
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldCompleteness {
+    pub field: String,
+    pub missing: usize,
+    pub missing_percentage: f64,
+}
+
+/// Projects sharing the same normalized value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    pub value: String,
+    pub project_ids: Vec<String>,
+}
+
+/// Different spellings of what is probably the same value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellingGroup {
+    pub normalized: String,
+    pub variants: Vec<SpellingVariant>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellingVariant {
+    pub value: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundingOutlier {
+    pub project_id: String,
+    pub title: String,
+    pub total_awarded: f64,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnparseableValue {
+    pub project_id: String,
+    pub title: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingEnrichment {
+    pub project_id: String,
+    pub title: String,
+    /// Enrichment sources without a record for this project
+    pub missing: Vec<String>,
+}
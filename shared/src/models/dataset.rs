@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+/// A record from the structured dataset that could not be parsed into a `Project`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectedRecord {
+    pub category: String,
+    pub index: usize,
+    pub title: Option<String>,
+    pub reason: String,
+}
+
+/// Summary of a dataset load, including every record that was rejected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetLoadReport {
+    pub source_path: String,
+    pub loaded_at: DateTime<Utc>,
+    pub total_records: usize,
+    pub loaded_count: usize,
+    pub rejected_count: usize,
+    pub rejected_by_category: BTreeMap<String, usize>,
+    pub rejected: Vec<RejectedRecord>,
+    /// Field name to the values that didn't map to a known variant, with occurrence counts
+    #[serde(default)]
+    pub unknown_values: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+impl DatasetLoadReport {
+    pub fn new(source_path: impl Into<String>) -> Self {
+        Self {
+            source_path: source_path.into(),
+            loaded_at: Utc::now(),
+            total_records: 0,
+            loaded_count: 0,
+            rejected_count: 0,
+            rejected_by_category: BTreeMap::new(),
+            rejected: Vec::new(),
+            unknown_values: BTreeMap::new(),
+        }
+    }
+
+    pub fn record_loaded(&mut self) {
+        self.total_records += 1;
+        self.loaded_count += 1;
+    }
+
+    pub fn record_rejected(&mut self, record: RejectedRecord) {
+        self.total_records += 1;
+        self.rejected_count += 1;
+        *self
+            .rejected_by_category
+            .entry(record.category.clone())
+            .or_insert(0) += 1;
+        self.rejected.push(record);
+    }
+
+    pub fn record_unknown(&mut self, field: &str, value: String) {
+        *self
+            .unknown_values
+            .entry(field.to_string())
+            .or_default()
+            .entry(value)
+            .or_insert(0) += 1;
+    }
+}
+
+/// A dataset that loaded but failed validation, kept so its rejected records can be inspected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefusedLoad {
+    pub reason: String,
+    pub report: DatasetLoadReport,
+}
+
+/// The report of the dataset being served, plus the most recent load refused since it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetReports {
+    pub active: DatasetLoadReport,
+    pub refused: Option<RefusedLoad>,
+}
+
+/// A versioned, immutable copy of an imported dataset
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DatasetSnapshot {
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+    pub checksum: String,
+    pub source_path: String,
+    pub project_count: usize,
+    /// Checksum of the enrichment files loaded with it. Pinned snapshots are served the current
+    /// enrichment files; when their checksum differs from this one, the enrichment has changed.
+    #[serde(default)]
+    pub enrichment_checksum: Option<String>,
+}
+
+/// A single field that differs between two versions of a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectChange {
+    pub id: String,
+    pub title: String,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub from: DatasetSnapshot,
+    pub to: DatasetSnapshot,
+    /// Ids of projects only present in `to`
+    pub added: Vec<String>,
+    /// Ids of projects only present in `from`
+    pub removed: Vec<String>,
+    pub changed: Vec<ProjectChange>,
+}
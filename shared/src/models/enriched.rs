@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WebsiteMetadata {
     pub page_title: Option<String>,
     pub meta_description: Option<String>,
@@ -17,7 +17,7 @@ pub struct WebsiteMetadata {
     pub app_stores: AppStores,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TechnologyStack {
     pub stellar: Vec<String>,
     pub blockchain: Vec<String>,
@@ -25,7 +25,7 @@ pub struct TechnologyStack {
     pub frameworks: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WebsiteSections {
     pub has_docs: bool,
     pub has_blog: bool,
@@ -34,13 +34,13 @@ pub struct WebsiteSections {
     pub has_pricing: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AppStores {
     pub google_play: bool,
     pub app_store: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SocialLinks {
     pub discord: Option<String>,
     pub twitter: Option<String>,
@@ -51,6 +51,43 @@ pub struct SocialLinks {
     pub social_score: f64,
 }
 
+/// Team details parsed from the project's application
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamProfile {
+    pub team_description: Option<String>,
+    pub team_size: Option<u32>,
+    #[serde(default)]
+    pub roles_mentioned: Vec<String>,
+    #[serde(default)]
+    pub expertise_areas: Vec<String>,
+    #[serde(default)]
+    pub linkedin_profiles: u32,
+    #[serde(default)]
+    pub has_team_desc: bool,
+    #[serde(default)]
+    pub team_strength_score: f64,
+}
+
+/// Standardized country and region from the geographic enrichment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RegionInfo {
+    pub country_standardized: Option<String>,
+    pub region: Option<String>,
+}
+
+/// Enrichment inlined into a project response; only the requested parts are present
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProjectEnrichment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub social: Option<SocialLinks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<WebsiteMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<RegionInfo>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscordInfo {
     pub server_name: Option<String>,
@@ -1,13 +1,23 @@
 pub mod user;
 pub mod project;
+pub mod project_fields;
+pub mod country;
 pub mod category;
 pub mod analytics;
 pub mod enriched;
 pub mod handbook;
+pub mod dataset;
+pub mod data_quality;
+pub mod saved_search;
 
 pub use user::*;
 pub use project::*;
+pub use project_fields::*;
+pub use country::*;
 pub use category::*;
 pub use analytics::*;
 pub use enriched::*;
 pub use handbook::*;
+pub use dataset::*;
+pub use data_quality::*;
+pub use saved_search::*;
@@ -1,9 +1,16 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use crate::models::category::ProjectCategory;
+use crate::models::country::Country;
+use crate::models::project_fields::{
+    Chain, IntegrationStatus, OpenSource, ParsedFields, Program, ProjectType, Quarter, Round,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct Project {
+    /// Stable identifier derived from the title at import; kept when the record already has one
+    #[serde(default)]
+    pub id: String,
     pub title: String,
     #[serde(rename = "type", alias = "project_type")]
     pub project_type: Option<String>,
@@ -33,6 +40,20 @@ pub struct Project {
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub announcement_link: Option<String>,
+    /// Typed copies of the raw text fields above; filled by `parse_fields`
+    #[serde(skip)]
+    pub parsed: ParsedFields,
+}
+
+/// Why a project matched a search query
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchMatch {
+    /// BM25 relevance; only comparable within one query
+    pub score: f64,
+    /// The field that contributed most to the score
+    pub field: String,
+    /// HTML-escaped excerpt of that field with matched words wrapped in `<mark>`
+    pub snippet: String,
 }
 
 impl Project {
@@ -51,16 +72,148 @@ impl Project {
     pub fn uses_soroban(&self) -> bool {
         self.soroban.unwrap_or(false)
     }
+
+    /// Parse the raw text fields into `parsed`, returning `(field, value)` for every
+    /// value that did not map to a known variant
+    pub fn parse_fields(&mut self) -> Vec<(&'static str, String)> {
+        let mut quarters: Vec<Quarter> = ParsedFields::split_list(self.quarters.as_deref())
+            .filter_map(Quarter::parse)
+            .collect();
+        quarters.sort();
+        quarters.dedup();
+
+        self.parsed = ParsedFields {
+            rounds: ParsedFields::split_list(self.rounds.as_deref())
+                .filter_map(Round::parse)
+                .collect(),
+            quarters,
+            programs: ParsedFields::split_list(self.programs.as_deref())
+                .map(Program::parse)
+                .collect(),
+            chains: ParsedFields::split_list(self.other_chains.as_deref())
+                .filter(|c| !matches!(c.to_lowercase().as_str(), "not multichain" | "no" | "stellar"))
+                .map(Chain::parse)
+                .collect(),
+            regions: ParsedFields::split_list(self.regions.as_deref())
+                .map(String::from)
+                .collect(),
+            country: self.country.as_deref().and_then(Country::lookup),
+            integration_status: self.integration_status.as_deref().and_then(IntegrationStatus::parse),
+            open_source: self.open_source.as_deref().and_then(OpenSource::parse),
+            project_type: self.project_type.as_deref().and_then(ProjectType::parse),
+        };
+
+        let mut unknown = Vec::new();
+        let checks = [
+            ("country", &self.country, self.parsed.country.is_some()),
+            ("integration_status", &self.integration_status, self.parsed.integration_status.is_some()),
+            ("open_source", &self.open_source, self.parsed.open_source.is_some()),
+            ("type", &self.project_type, self.parsed.project_type.is_some()),
+        ];
+        for (field, raw, known) in checks {
+            if let Some(value) = raw.as_deref().filter(|v| !known && !ParsedFields::is_placeholder(v)) {
+                unknown.push((field, value.trim().to_string()));
+            }
+        }
+        unknown
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        self.parsed.integration_status == Some(IntegrationStatus::Mainnet)
+    }
+
+    /// Fully or partly open source
+    pub fn is_open_source(&self) -> bool {
+        self.parsed.open_source.is_some_and(|o| o.is_open())
+    }
+
+    /// Normalized type name, falling back to the raw value for types outside the taxonomy
+    pub fn type_name(&self) -> Option<&str> {
+        self.parsed
+            .project_type
+            .as_ref()
+            .map(ProjectType::as_str)
+            .or(self.project_type.as_deref())
+    }
+
+    /// ISO short name, falling back to the raw value for countries outside the table
+    pub fn country_name(&self) -> Option<&str> {
+        self.parsed
+            .country
+            .map(|country| country.name)
+            .or(self.country.as_deref().map(str::trim).filter(|c| !ParsedFields::is_placeholder(c)))
+    }
+
+    pub fn first_quarter(&self) -> Option<Quarter> {
+        self.parsed.quarters.first().copied()
+    }
+
+    pub fn latest_quarter(&self) -> Option<Quarter> {
+        self.parsed.quarters.last().copied()
+    }
+
+    pub fn is_multichain(&self) -> bool {
+        !self.parsed.chains.is_empty()
+    }
+}
+
+/// URL-safe slug: lowercase ASCII alphanumerics with single hyphens between words
+pub fn slugify(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// A list given either as a sequence or as one comma-separated string, so
+/// `?countries=US,KE` and JSON `["US", "KE"]` both work
+pub fn deserialize_list<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrList {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    let values = match Option::<StringOrList>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(StringOrList::One(value)) => value.split(',').map(String::from).collect(),
+        Some(StringOrList::Many(values)) => values,
+    };
+
+    Ok(Some(
+        values
+            .into_iter()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .collect(),
+    ))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectFilter {
     // Text Search
     pub search_query: Option<String>,
+    /// Query language, e.g. `category:defi funding>=100k -chain:ethereum`
+    pub q: Option<String>,
 
     // Category & Type
     pub category: Option<String>,
     pub project_type: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_list")]
     pub categories: Option<Vec<String>>, // Multiple category filter
 
     // Funding Range
@@ -74,19 +227,23 @@ pub struct ProjectFilter {
     pub has_github: Option<bool>,
     pub has_mainnet: Option<bool>,
     pub is_open_source: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_list")]
     pub other_chains: Option<Vec<String>>,
 
     // Status & Programs
     pub status: Option<String>,
     pub integration_status: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_list")]
     pub programs: Option<Vec<String>>, // Kickstart, Build, Growth Hack, etc.
     pub rounds: Option<String>, // "1", "2", "3", "4+"
     pub min_rounds: Option<u32>,
 
     // Geographic
     pub country: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_list")]
     pub countries: Option<Vec<String>>,
     pub region: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_list")]
     pub regions: Option<Vec<String>>,
 
     // Social & Presence
@@ -97,21 +254,27 @@ pub struct ProjectFilter {
 
     // Temporal
     pub quarter: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_list")]
     pub quarters: Option<Vec<String>>,
     pub year: Option<String>,
 
     // Sorting
+    /// Keys with directions, e.g. `funding:desc,rounds:desc,title:asc`; takes precedence over `sort_by`
+    pub sort: Option<String>,
     pub sort_by: Option<String>, // "funding_desc", "funding_asc", "name_asc", "name_desc", "recent"
 
     // Pagination
     pub page: Option<usize>,
     pub per_page: Option<usize>,
+    /// Opaque `next_cursor`/`prev_cursor` from a previous page; takes precedence over `page`
+    pub cursor: Option<String>,
 }
 
 impl Default for ProjectFilter {
     fn default() -> Self {
         Self {
             search_query: None,
+            q: None,
             category: None,
             project_type: None,
             categories: None,
@@ -140,9 +303,11 @@ impl Default for ProjectFilter {
             quarter: None,
             quarters: None,
             year: None,
+            sort: None,
             sort_by: Some("funding_desc".to_string()),
             page: Some(1),
             per_page: Some(20),
+            cursor: None,
         }
     }
 }
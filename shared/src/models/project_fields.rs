@@ -0,0 +1,493 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
+use crate::models::country::Country;
+
+/// A funding round such as "SCF #26"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Round {
+    /// Round number when the label contains one
+    pub number: Option<u32>,
+    pub label: String,
+}
+
+impl Round {
+    pub fn parse(s: &str) -> Option<Self> {
+        let label = s.trim();
+        if label.is_empty() {
+            return None;
+        }
+
+        let digits: String = label
+            .rsplit(|c: char| !c.is_ascii_digit())
+            .find(|part| !part.is_empty())
+            .unwrap_or_default()
+            .to_string();
+
+        Some(Self {
+            number: digits.parse().ok(),
+            label: label.to_string(),
+        })
+    }
+}
+
+/// A calendar quarter such as "Q2 '23". Only quarters within `Quarter::YEARS` exist, so every
+/// label names one quarter; deserializing anything else fails.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(try_from = "QuarterFields")]
+pub struct Quarter {
+    year: u16,
+    q: u8,
+}
+
+#[derive(Deserialize)]
+struct QuarterFields {
+    year: u16,
+    q: u8,
+}
+
+impl TryFrom<QuarterFields> for Quarter {
+    type Error = String;
+
+    fn try_from(fields: QuarterFields) -> Result<Self, Self::Error> {
+        Self::new(fields.year, fields.q).ok_or_else(|| {
+            let (first, last) = (Self::YEARS.start(), Self::YEARS.end());
+            format!("Q{} {} is not a quarter between {} and {}", fields.q, fields.year, first, last)
+        })
+    }
+}
+
+impl Quarter {
+    /// Years a funding quarter can plausibly fall in; two-digit years are read as 20xx
+    pub const YEARS: RangeInclusive<u16> = 2000..=2099;
+
+    pub fn new(year: u16, q: u8) -> Option<Self> {
+        ((1..=4).contains(&q) && Self::YEARS.contains(&year)).then_some(Self { year, q })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn q(&self) -> u8 {
+        self.q
+    }
+
+    /// Accepts "Q2 '23", "Q2 23", "Q2 2023", "2023 Q2" and "2023-Q2"
+    pub fn parse(s: &str) -> Option<Self> {
+        let upper = s.trim().to_uppercase();
+        let q_pos = upper.find('Q')?;
+        let q = upper[q_pos + 1..].chars().next()?.to_digit(10)? as u8;
+
+        // The year is whichever digits are not the quarter number
+        let rest = format!("{} {}", &upper[..q_pos], &upper[q_pos + 2..]);
+        let digits: String = rest.chars().filter(|c| c.is_ascii_digit()).collect();
+        let year = match digits.len() {
+            2 => 2000 + digits.parse::<u16>().ok()?,
+            4 => digits.parse().ok()?,
+            _ => return None,
+        };
+
+        Self::new(year, q)
+    }
+
+    /// Quarters since year 0, so consecutive quarters differ by one
+    pub fn ordinal(&self) -> u32 {
+        self.year as u32 * 4 + (self.q as u32 - 1)
+    }
+
+    /// The quarter with this ordinal; ordinals between those of two quarters are quarters too
+    pub fn from_ordinal(ordinal: u32) -> Self {
+        Self {
+            year: (ordinal / 4) as u16,
+            q: (ordinal % 4) as u8 + 1,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        Self::from_ordinal(self.ordinal() + 1)
+    }
+
+    /// Label in the dataset's own format, e.g. "Q2 '23"; years outside `YEARS` (only reachable
+    /// through `next`) keep all four digits
+    pub fn label(&self) -> String {
+        if Self::YEARS.contains(&self.year) {
+            format!("Q{} '{:02}", self.q, self.year % 100)
+        } else {
+            format!("Q{} {}", self.q, self.year)
+        }
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Q{} {}", self.q, self.year)
+    }
+}
+
+/// An SCF program a project received funding through
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(from = "String", into = "String")]
+pub enum Program {
+    Kickstart,
+    Build,
+    GrowthHack,
+    AuditBank,
+    Other(String),
+}
+
+impl Program {
+    pub fn parse(s: &str) -> Self {
+        let name = s.trim();
+        match name.to_lowercase().replace(['-', '_'], " ").as_str() {
+            "kickstart" | "scf kickstart" => Self::Kickstart,
+            "build" | "scf build" => Self::Build,
+            "growth hack" | "growthhack" | "scf growth hack" => Self::GrowthHack,
+            "audit bank" | "auditbank" | "scf audit bank" => Self::AuditBank,
+            _ => Self::Other(name.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Kickstart => "Kickstart",
+            Self::Build => "Build",
+            Self::GrowthHack => "Growth Hack",
+            Self::AuditBank => "Audit Bank",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for Program {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+impl From<Program> for String {
+    fn from(program: Program) -> Self {
+        program.as_str().to_string()
+    }
+}
+
+/// A blockchain other than Stellar that a project also deploys to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(from = "String", into = "String")]
+pub enum Chain {
+    Ethereum,
+    Solana,
+    Polygon,
+    Bnb,
+    Avalanche,
+    Bitcoin,
+    Cosmos,
+    Polkadot,
+    Near,
+    Other(String),
+}
+
+impl Chain {
+    pub fn parse(s: &str) -> Self {
+        let name = s.trim();
+        match name.to_lowercase().as_str() {
+            "ethereum" | "eth" | "evm" => Self::Ethereum,
+            "solana" | "sol" => Self::Solana,
+            "polygon" | "matic" => Self::Polygon,
+            "bnb" | "bnb chain" | "bsc" | "binance smart chain" => Self::Bnb,
+            "avalanche" | "avax" => Self::Avalanche,
+            "bitcoin" | "btc" => Self::Bitcoin,
+            "cosmos" => Self::Cosmos,
+            "polkadot" | "dot" => Self::Polkadot,
+            "near" => Self::Near,
+            _ => Self::Other(name.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ethereum => "Ethereum",
+            Self::Solana => "Solana",
+            Self::Polygon => "Polygon",
+            Self::Bnb => "BNB Chain",
+            Self::Avalanche => "Avalanche",
+            Self::Bitcoin => "Bitcoin",
+            Self::Cosmos => "Cosmos",
+            Self::Polkadot => "Polkadot",
+            Self::Near => "NEAR",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for Chain {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+impl From<Chain> for String {
+    fn from(chain: Chain) -> Self {
+        chain.as_str().to_string()
+    }
+}
+
+/// How far a project's Stellar integration has progressed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IntegrationStatus {
+    Development,
+    Testnet,
+    Mainnet,
+}
+
+impl IntegrationStatus {
+    /// Words that negate the stage named in the same clause; "pre" and "non" also cover "pre-mainnet"
+    const NEGATIONS: [&'static str; 7] = ["not", "no", "non", "never", "without", "pre", "isn't"];
+
+    /// Matches whole words only and skips negated clauses, so "Testnet, not on mainnet yet" is
+    /// Testnet and "pre-mainnet" names no stage. Of several stages, the furthest wins.
+    pub fn parse(s: &str) -> Option<Self> {
+        let status = s.trim().to_lowercase();
+        status
+            .split([',', ';', '/', '(', ')'])
+            .flat_map(|clause| {
+                let words: Vec<&str> = clause
+                    .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+                    .filter(|word| !word.is_empty())
+                    .collect();
+                words.split(|word| *word == "but").map(<[&str]>::to_vec).collect::<Vec<_>>()
+            })
+            .filter(|words| !words.iter().any(|word| Self::NEGATIONS.contains(word)))
+            .filter_map(|words| Self::parse_clause(&words))
+            .max()
+    }
+
+    fn parse_clause(words: &[&str]) -> Option<Self> {
+        let has = |word: &str| words.contains(&word);
+        if has("mainnet") || words.first() == Some(&"live") {
+            Some(Self::Mainnet)
+        } else if has("testnet") || has("futurenet") {
+            Some(Self::Testnet)
+        } else if ["development", "prototype", "building", "idea"].iter().any(|stage| has(stage))
+            || words.windows(2).any(|pair| pair == ["in", "progress"])
+        {
+            Some(Self::Development)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Development => "Development",
+            Self::Testnet => "Testnet",
+            Self::Mainnet => "Mainnet",
+        }
+    }
+}
+
+/// Whether a project's code is public
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum OpenSource {
+    Full,
+    Partial,
+    Closed,
+}
+
+impl OpenSource {
+    /// Accepts the dataset's "Fully/Partly/Not Open-Source" as well as yes/no and true/false
+    pub fn parse(s: &str) -> Option<Self> {
+        let value = s.trim().to_lowercase();
+        if value.starts_with("no") || value.contains("closed") || value == "false" {
+            Some(Self::Closed)
+        } else if value.contains("part") {
+            Some(Self::Partial)
+        } else if value.contains("open") || matches!(value.as_str(), "yes" | "true" | "fully") {
+            Some(Self::Full)
+        } else {
+            None
+        }
+    }
+
+    /// Some or all of the code is public
+    pub fn is_open(&self) -> bool {
+        matches!(self, Self::Full | Self::Partial)
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Full => "Fully Open-Source",
+            Self::Partial => "Partly Open-Source",
+            Self::Closed => "Not Open-Source",
+        }
+    }
+}
+
+/// What a project builds, independent of its funding category
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProjectType {
+    Dex,
+    Lending,
+    Payments,
+    Wallet,
+    Anchor,
+    Stablecoin,
+    Oracle,
+    Bridge,
+    Marketplace,
+    Nft,
+    Gaming,
+    Analytics,
+    Identity,
+    DeveloperTooling,
+    Infrastructure,
+    Protocol,
+    Education,
+    Community,
+}
+
+impl ProjectType {
+    pub fn parse(s: &str) -> Option<Self> {
+        let value = s.trim().to_lowercase().replace(['-', '_'], " ");
+        let project_type = match value.as_str() {
+            "dex" | "amm" | "exchange" | "decentralized exchange" => Self::Dex,
+            "lending" | "borrowing" | "lending protocol" => Self::Lending,
+            "payments" | "payment" | "remittance" | "remittances" => Self::Payments,
+            "wallet" | "wallets" => Self::Wallet,
+            "anchor" | "on ramp" | "off ramp" | "ramp" => Self::Anchor,
+            "stablecoin" | "stablecoins" => Self::Stablecoin,
+            "oracle" | "oracles" => Self::Oracle,
+            "bridge" | "bridges" | "cross chain" => Self::Bridge,
+            "marketplace" => Self::Marketplace,
+            "nft" | "nfts" => Self::Nft,
+            "gaming" | "game" | "games" => Self::Gaming,
+            "analytics" | "data" | "explorer" | "data and analytics" => Self::Analytics,
+            "identity" | "kyc" => Self::Identity,
+            "developer tooling" | "developer tools" | "sdk" | "tooling" => Self::DeveloperTooling,
+            "infrastructure" => Self::Infrastructure,
+            "protocol" | "defi" => Self::Protocol,
+            "education" => Self::Education,
+            "community" | "dao" => Self::Community,
+            _ => return None,
+        };
+        Some(project_type)
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Dex => "DEX",
+            Self::Lending => "Lending",
+            Self::Payments => "Payments",
+            Self::Wallet => "Wallet",
+            Self::Anchor => "Anchor",
+            Self::Stablecoin => "Stablecoin",
+            Self::Oracle => "Oracle",
+            Self::Bridge => "Bridge",
+            Self::Marketplace => "Marketplace",
+            Self::Nft => "NFT",
+            Self::Gaming => "Gaming",
+            Self::Analytics => "Analytics",
+            Self::Identity => "Identity",
+            Self::DeveloperTooling => "Developer Tooling",
+            Self::Infrastructure => "Infrastructure",
+            Self::Protocol => "Protocol",
+            Self::Education => "Education",
+            Self::Community => "Community",
+        }
+    }
+}
+
+/// Typed views of the comma-joined text fields, parsed once when a dataset is loaded
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedFields {
+    pub rounds: Vec<Round>,
+    /// Sorted, without duplicates
+    pub quarters: Vec<Quarter>,
+    pub programs: Vec<Program>,
+    /// Empty for Stellar-only projects
+    pub chains: Vec<Chain>,
+    pub regions: Vec<String>,
+    pub country: Option<&'static Country>,
+    pub integration_status: Option<IntegrationStatus>,
+    pub open_source: Option<OpenSource>,
+    pub project_type: Option<ProjectType>,
+}
+
+impl ParsedFields {
+    /// Split a comma-joined field, dropping blanks and placeholder values
+    pub fn split_list(value: Option<&str>) -> impl Iterator<Item = &str> {
+        value
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !Self::is_placeholder(item))
+    }
+
+    /// Blank or a stand-in for a missing value
+    pub fn is_placeholder(value: &str) -> bool {
+        matches!(value.trim().to_lowercase().as_str(), "" | "none" | "n/a" | "-")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quarter_parse_formats() {
+        let expected = Quarter::new(2023, 2);
+
+        assert_eq!(Quarter::parse("Q2 '23"), expected);
+        assert_eq!(Quarter::parse(" q2 2023 "), expected);
+        assert_eq!(Quarter::parse("2023-Q2"), expected);
+        assert_eq!(Quarter::parse("Q5 '23"), None);
+        assert_eq!(Quarter::parse("Recent"), None);
+        assert_eq!(Quarter::parse("Q2 '23").unwrap().label(), "Q2 '23");
+        assert_eq!(Quarter::new(2023, 4).unwrap().next(), Quarter::new(2024, 1).unwrap());
+    }
+
+    #[test]
+    fn test_quarter_bounds() {
+        assert_eq!(Quarter::parse("Q2 1850"), None);
+        assert_eq!(Quarter::parse("Q2 9999"), None);
+        assert_eq!(Quarter::new(2023, 0), None);
+        assert_eq!(Quarter::new(2099, 4).unwrap().next().label(), "Q1 2100");
+
+        assert!(serde_json::from_str::<Quarter>(r#"{"year":2023,"q":0}"#).is_err());
+        assert!(serde_json::from_str::<Quarter>(r#"{"year":1923,"q":2}"#).is_err());
+        let quarter: Quarter = serde_json::from_str(r#"{"year":2023,"q":2}"#).unwrap();
+        assert_eq!(quarter, Quarter::new(2023, 2).unwrap());
+        assert_eq!(serde_json::to_string(&quarter).unwrap(), r#"{"year":2023,"q":2}"#);
+    }
+
+    #[test]
+    fn test_round_and_program_parse() {
+        assert_eq!(Round::parse("SCF #26").unwrap().number, Some(26));
+        assert_eq!(Round::parse("Pilot").unwrap().number, None);
+        assert_eq!(Program::parse("SCF Build"), Program::Build);
+        assert_eq!(Program::parse("growth-hack"), Program::GrowthHack);
+        assert_eq!(Program::parse("Kickstart").as_str(), "Kickstart");
+        assert_eq!(Program::parse("SCF Build").as_str(), "Build");
+    }
+
+    #[test]
+    fn test_status_and_open_source_parse() {
+        assert_eq!(IntegrationStatus::parse("Live on Mainnet"), Some(IntegrationStatus::Mainnet));
+        assert_eq!(IntegrationStatus::parse("testnet"), Some(IntegrationStatus::Testnet));
+        assert_eq!(IntegrationStatus::parse("Beta"), None);
+        assert_eq!(IntegrationStatus::parse("Live"), Some(IntegrationStatus::Mainnet));
+        assert_eq!(IntegrationStatus::parse("In-development"), Some(IntegrationStatus::Development));
+        assert_eq!(IntegrationStatus::parse("pre-mainnet"), None);
+        assert_eq!(IntegrationStatus::parse("Not on mainnet"), None);
+        assert_eq!(IntegrationStatus::parse("mainnets"), None);
+        assert_eq!(IntegrationStatus::parse("Testnet, not on mainnet yet"), Some(IntegrationStatus::Testnet));
+        assert_eq!(IntegrationStatus::parse("testnet but no mainnet"), Some(IntegrationStatus::Testnet));
+        assert_eq!(IntegrationStatus::parse("Prototype (testnet)"), Some(IntegrationStatus::Testnet));
+        assert_eq!(IntegrationStatus::parse("isn't live"), None);
+        assert_eq!(OpenSource::parse("Fully Open-Source"), Some(OpenSource::Full));
+        assert_eq!(OpenSource::parse("Partly Open-Source"), Some(OpenSource::Partial));
+        assert_eq!(OpenSource::parse("Not Open-Source"), Some(OpenSource::Closed));
+        assert_eq!(OpenSource::parse("yes"), Some(OpenSource::Full));
+        assert_eq!(ProjectType::parse("Developer-Tooling"), Some(ProjectType::DeveloperTooling));
+    }
+}
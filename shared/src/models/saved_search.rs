@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::models::ProjectFilter;
+
+/// A named project filter a user can re-run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub id: Uuid,
+    pub name: String,
+    /// Stored without `page`, `per_page` and `cursor`
+    pub filter: ProjectFilter,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Unset until the first run
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub result_count: Option<usize>,
+    /// Change in `result_count` at the last run; unset before the second run
+    pub result_delta: Option<i64>,
+}
@@ -0,0 +1,51 @@
+-- Create projects table (imported from data/raw/all_projects_structured.json)
+CREATE TABLE IF NOT EXISTS projects (
+    id BIGSERIAL PRIMARY KEY,
+    -- Stable project id, assigned by the importer (see DatasetService::assign_ids)
+    slug VARCHAR(500) NOT NULL UNIQUE,
+    title VARCHAR(500) NOT NULL,
+    project_type VARCHAR(255),
+    company VARCHAR(500),
+    country VARCHAR(255),
+    description TEXT,
+    category VARCHAR(255),
+    total_awarded DOUBLE PRECISION,
+    programs TEXT,
+    rounds TEXT,
+    quarters TEXT,
+    integration_status VARCHAR(255),
+    open_source VARCHAR(255),
+    website TEXT,
+    github TEXT,
+    soroban BOOLEAN,
+    other_chains TEXT,
+    regions TEXT,
+    traction TEXT,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    funding_details TEXT,
+    status VARCHAR(255),
+    announcement_link TEXT,
+    -- Normalized by the importer the way the API parses each project, so SQL filters and sorts
+    -- agree with the in-memory dataset; re-import after upgrading the parsing rules
+    normalized_category VARCHAR(255) NOT NULL,
+    normalized_type VARCHAR(255),
+    country_code VARCHAR(2),
+    integration_stage VARCHAR(255),
+    is_open_source BOOLEAN NOT NULL,
+    is_multichain BOOLEAN NOT NULL,
+    chain_names TEXT[] NOT NULL DEFAULT '{}',
+    program_names TEXT[] NOT NULL DEFAULT '{}',
+    round_count INTEGER NOT NULL,
+    quarter_ordinals INTEGER[] NOT NULL DEFAULT '{}',
+    latest_quarter INTEGER,
+    sort_title TEXT NOT NULL,
+    sort_country TEXT,
+    imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create indexes for common filters and sorts
+CREATE INDEX IF NOT EXISTS idx_projects_title ON projects(title);
+CREATE INDEX IF NOT EXISTS idx_projects_normalized_category ON projects(normalized_category);
+CREATE INDEX IF NOT EXISTS idx_projects_country_code ON projects(country_code);
+CREATE INDEX IF NOT EXISTS idx_projects_total_awarded ON projects(total_awarded);
+CREATE INDEX IF NOT EXISTS idx_projects_sort_title ON projects(sort_title COLLATE "C");
@@ -0,0 +1,67 @@
+-- Favorites reference projects by their stable id (projects.slug); project_title is kept for display.
+-- Ids are only assigned by the importer, so import the projects before applying this migration.
+ALTER TABLE user_favorites ADD COLUMN IF NOT EXISTS project_id VARCHAR(500);
+
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM user_favorites WHERE project_id IS NULL)
+        AND NOT EXISTS (SELECT 1 FROM projects) THEN
+        RAISE EXCEPTION 'projects is empty: run `web-server import` before resolving favorites to project ids';
+    END IF;
+END $$;
+
+-- Take the id of the imported project with that title, preferring the one in the favorite's
+-- saved category; titles no longer in the table fall back to the bare slug
+UPDATE user_favorites f
+SET project_id = COALESCE(
+    (
+        SELECT p.slug
+        FROM projects p
+        WHERE p.title = f.project_title
+        ORDER BY (p.category IS NOT DISTINCT FROM f.project_data->>'category') DESC, p.id
+        LIMIT 1
+    ),
+    TRIM(BOTH '-' FROM LOWER(REGEXP_REPLACE(f.project_title, '[^A-Za-z0-9]+', '-', 'g')))
+)
+WHERE f.project_id IS NULL;
+
+ALTER TABLE user_favorites ALTER COLUMN project_id SET NOT NULL;
+
+-- Titles that differed only in punctuation can now name the same project twice for a user.
+-- Merge each such group into its earliest favorite, keeping every distinct note, so the unique
+-- index below can be built.
+WITH ranked AS (
+    SELECT
+        id,
+        notes,
+        saved_at,
+        FIRST_VALUE(id) OVER (PARTITION BY user_id, project_id ORDER BY saved_at, id) AS keeper,
+        COUNT(*) OVER (PARTITION BY user_id, project_id) AS copies
+    FROM user_favorites
+),
+distinct_notes AS (
+    SELECT DISTINCT ON (keeper, TRIM(notes)) keeper, notes, saved_at, id
+    FROM ranked
+    WHERE copies > 1 AND TRIM(notes) <> ''
+    ORDER BY keeper, TRIM(notes), saved_at, id
+),
+merged AS (
+    SELECT keeper, STRING_AGG(notes, E'\n\n' ORDER BY saved_at, id) AS notes
+    FROM distinct_notes
+    GROUP BY keeper
+)
+UPDATE user_favorites f
+SET notes = m.notes
+FROM merged m
+WHERE f.id = m.keeper;
+
+DELETE FROM user_favorites f
+USING user_favorites earlier
+WHERE f.user_id = earlier.user_id
+  AND f.project_id = earlier.project_id
+  AND (earlier.saved_at, earlier.id) < (f.saved_at, f.id);
+
+DROP INDEX IF EXISTS idx_user_favorites_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_user_favorites_unique
+    ON user_favorites(user_id, project_id);
+CREATE INDEX IF NOT EXISTS idx_user_favorites_project_id ON user_favorites(project_id);
@@ -0,0 +1,18 @@
+-- Named project filters per user, with the outcome of their last run
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(200) NOT NULL,
+    filter JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_run_at TIMESTAMPTZ,
+    result_count INTEGER,
+    result_delta INTEGER,
+    -- Project ids matched at the last run, to report what was added or removed since
+    result_ids TEXT[] NOT NULL DEFAULT '{}'
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_user_id ON saved_searches(user_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_saved_searches_user_name
+    ON saved_searches(user_id, name);
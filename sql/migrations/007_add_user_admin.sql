@@ -0,0 +1,2 @@
+-- Admins may use the /api/admin routes (dataset reload, import, reports)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS is_admin BOOLEAN NOT NULL DEFAULT FALSE;